@@ -0,0 +1,127 @@
+use crate::polynomial::Polynomial;
+use crate::term::{Term, VarFunction};
+
+/// A `Polynomial` evaluated through a table of its distinct `(variable, VarFunction)` factors.
+///
+/// The table and the position of each term's factors in it are computed at compile time, so
+/// `evaluate` calls every distinct function once and then only multiplies table entries. The
+/// result is bitwise identical to `Polynomial::evaluate`. Cross-variable factors are
+/// evaluated per term.
+///
+/// `NUM_FACTORS` must equal `Polynomial::factor_count()`; build it with `cache_poly!`.
+/// Like `HornerPoly` and `DensePoly`, this is a separate type rather than a mode of
+/// `Polynomial` because it owns compile-time tables sized by its own const parameter,
+/// which `Polynomial<NUM_VARIABLES>` has no room for.
+///
+/// # Example
+///
+/// ```
+/// use const_poly::VarFunction::*;
+/// use const_poly::{cache_poly, const_poly, CachedPolynomial, Polynomial};
+///
+/// // sin(x) is computed once for both terms
+/// const F: Polynomial<2> = const_poly!({[2.0, Sin, Pow(2)], [-1.0, Sin, Exp]});
+/// const C: CachedPolynomial<2, { F.factor_count() }> = cache_poly!(F);
+///
+/// assert_eq!(F.factor_count(), 3);
+/// assert_eq!(C.evaluate(&[0.5, 1.5]), F.evaluate(&[0.5, 1.5]));
+/// ```
+#[derive(Clone, Copy)]
+pub struct CachedPolynomial<const NUM_VARIABLES: usize, const NUM_FACTORS: usize> {
+    terms: &'static [Term<NUM_VARIABLES>],
+    factors: [(usize, VarFunction); NUM_FACTORS],
+    slots: &'static [[usize; NUM_VARIABLES]],
+}
+
+impl<const NUM_VARIABLES: usize, const NUM_FACTORS: usize>
+    CachedPolynomial<NUM_VARIABLES, NUM_FACTORS>
+{
+    /// Creates a new `CachedPolynomial` from a polynomial and its `factor_slots()`.
+    ///
+    /// Panics (at compile time, in a const context) if `NUM_FACTORS` differs from
+    /// `poly.factor_count()` or if `slots` do not match the factors of `poly`.
+    ///
+    /// # Parameters
+    ///
+    /// - `poly`: The polynomial to evaluate.
+    /// - `slots`: For each term and variable, the index of its factor in the factor table.
+    ///
+    /// # Returns
+    ///
+    /// A new `CachedPolynomial` instance.
+    pub const fn new(
+        poly: &Polynomial<NUM_VARIABLES>,
+        slots: &'static [[usize; NUM_VARIABLES]],
+    ) -> Self {
+        let terms = poly.terms;
+        let factors = poly.factor_table::<NUM_FACTORS>();
+        assert!(
+            slots.len() == terms.len(),
+            "slots must hold one entry per term"
+        );
+
+        let mut t = 0;
+        while t < terms.len() {
+            let mut i = 0;
+            while i < NUM_VARIABLES {
+                let (var, function) = factors[slots[t][i]];
+                assert!(
+                    var == i && function.same_as(&terms[t].functions[i]),
+                    "slots do not match the factors of the polynomial"
+                );
+                i += 1;
+            }
+            t += 1;
+        }
+
+        Self {
+            terms,
+            factors,
+            slots,
+        }
+    }
+
+    /// Evaluates the polynomial at the given variable values.
+    ///
+    /// # Parameters
+    ///
+    /// - `vars`: An array of `NUM_VARIABLES` floating-point values representing the variables.
+    ///
+    /// # Returns
+    ///
+    /// The floating-point result of evaluating the polynomial.
+    pub const fn evaluate(&self, vars: &[f64; NUM_VARIABLES]) -> f64 {
+        let mut values = [0.0; NUM_FACTORS];
+        let mut f = 0;
+
+        while f < NUM_FACTORS {
+            let (var, function) = self.factors[f];
+            values[f] = function.evaluate(vars[var]);
+            f += 1;
+        }
+
+        let mut sum = 0.0;
+        let mut t = 0;
+
+        while t < self.terms.len() {
+            let term = &self.terms[t];
+            let mut result = term.coeff;
+            let mut i = 0;
+
+            while i < NUM_VARIABLES {
+                result *= values[self.slots[t][i]];
+                i += 1;
+            }
+
+            let mut k = 0;
+            while k < term.cross.len() {
+                result *= term.cross[k].evaluate(vars);
+                k += 1;
+            }
+
+            sum += result;
+            t += 1;
+        }
+        sum
+    }
+}
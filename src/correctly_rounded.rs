@@ -0,0 +1,692 @@
+use crate::double_double::{DoubleDouble, binary_exponent, cos_dd, exp_dd, ln_dd, sin_dd, sqrt_dd};
+use crate::function_approximations::{abs, round, static_powi};
+
+// === Internal configuration constants ===
+
+/// Relative error assumed for the double-double kernels: 2^-98, about a hundred times their
+/// measured error. A fast-path result is only returned if every value within this bound
+/// rounds to the same f64.
+const FAST_PATH_RELATIVE_ERROR: f64 = 3.1554436208840472e-30;
+
+/// 2^-27: below this magnitude, sin(x) rounds to x and cos(x) rounds to 1.
+const SIN_COS_TINY: f64 = 7.450580596923828e-9;
+
+/// 2^-54: below this magnitude, e^x rounds to 1.
+const EXP_TINY: f64 = 5.551115123125783e-17;
+
+/// Arguments beyond which e^x certainly rounds to infinity or to zero.
+const EXP_OVERFLOW_CUTOFF: f64 = 710.0;
+const EXP_UNDERFLOW_CUTOFF: f64 = -746.0;
+
+/// Arguments for which `exp_dd()` keeps full precision; e^x is neither close to overflow
+/// nor subnormal in its low part.
+const EXP_FAST_PATH_MIN: f64 = -650.0;
+const EXP_FAST_PATH_MAX: f64 = 700.0;
+
+/// 2^-53, half an ulp of a number in [1, 2).
+const HALF_ULP_OF_ONE: f64 = 1.1102230246251565e-16;
+
+/// Number of fractional bits of `Fixed`.
+const FRACTION_BITS: i32 = 192;
+
+/// ln(2) and π/2 in the `Fixed` format, truncated after 192 fractional bits.
+const LN2_FIXED: Fixed = Fixed::from_magnitude([
+    0x40f343267298b62d,
+    0xc9e3b39803f2f6af,
+    0xb17217f7d1cf79ab,
+    0x0000000000000000,
+]);
+const HALF_PI_FIXED: Fixed = Fixed::from_magnitude([
+    0x52049c1114cf98e8,
+    0x898cc51701b839a2,
+    0x921fb54442d18469,
+    0x0000000000000001,
+]);
+
+/// The first 1408 bits of 2/π, most significant first, enough to reduce any finite f64
+/// modulo π/2.
+const TWO_OVER_PI_BITS: [u64; 22] = [
+    0xa2f9836e4e441529,
+    0xfc2757d1f534ddc0,
+    0xdb6295993c439041,
+    0xfe5163abdebbc561,
+    0xb7246e3a424dd2e0,
+    0x06492eea09d1921c,
+    0xfe1deb1cb129a73e,
+    0xe88235f52ebb4484,
+    0xe99c7026b45f7e41,
+    0x3991d639835339f4,
+    0x9c845f8bbdf9283b,
+    0x1ff897ffde05980f,
+    0xef2f118b5a0a6d1f,
+    0x6d367ecf27cb09b7,
+    0x4f463f669e5fea2d,
+    0x7527bac7ebe5f17b,
+    0x3d0739f78a5292ea,
+    0x6bfb5fb11f8d5d08,
+    0x56033046fc7b6bab,
+    0xf0cfbc209af4361d,
+    0xa9e391615ee61b08,
+    0x6599855f14a06840,
+];
+
+/// Number of 64-bit words of 2/π multiplied with the significand during argument reduction.
+const REDUCTION_WINDOW_WORDS: usize = 6;
+
+// ========================================
+
+/// Computes sin(x) correctly rounded to the nearest f64.
+///
+/// The argument is reduced exactly modulo π/2 for any finite `x`. The result comes from
+/// `sin_dd()` unless it lies too close to a rounding boundary, in which case it is
+/// recomputed with 192-bit fixed-point arithmetic.
+///
+/// Returns NaN for infinite or NaN inputs.
+///
+/// # Example
+///
+/// ```
+/// use const_poly::correctly_rounded::sin_cr;
+///
+/// const VALUE: f64 = sin_cr(1e22);
+/// assert_eq!(VALUE, -0.8522008497671888);
+/// ```
+pub const fn sin_cr(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    if abs(x) < SIN_COS_TINY {
+        return x;
+    }
+    sin_quadrant_cr(x, 0)
+}
+
+/// Computes cos(x) correctly rounded to the nearest f64, in the same way as `sin_cr()`.
+///
+/// Returns NaN for infinite or NaN inputs.
+pub const fn cos_cr(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+    if abs(x) < SIN_COS_TINY {
+        return 1.0;
+    }
+    sin_quadrant_cr(x, 1)
+}
+
+/// Computes e^x correctly rounded to the nearest f64, including subnormal results.
+///
+/// The result comes from `exp_dd()` unless it lies too close to a rounding boundary or is
+/// near overflow or underflow, in which case it is recomputed with 192-bit fixed-point
+/// arithmetic.
+///
+/// # Example
+///
+/// ```
+/// use const_poly::correctly_rounded::exp_cr;
+///
+/// // e^x lies within 2^-110 of the midpoint between two f64 values here
+/// const VALUE: f64 = exp_cr(7.541752774995959e-10);
+/// assert_eq!(VALUE, 1.0000000007541754);
+/// ```
+pub const fn exp_cr(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > EXP_OVERFLOW_CUTOFF {
+        return f64::INFINITY;
+    }
+    if x < EXP_UNDERFLOW_CUTOFF {
+        return 0.0;
+    }
+    if abs(x) < EXP_TINY {
+        return 1.0;
+    }
+
+    if x >= EXP_FAST_PATH_MIN
+        && x <= EXP_FAST_PATH_MAX
+        && let Some(result) = round_if_unambiguous(exp_dd(DoubleDouble::from_f64(x)))
+    {
+        return result;
+    }
+
+    // e^x = e^r · 2^k with r = x - k·ln(2)
+    let k = round(x / core::f64::consts::LN_2);
+    let r = Fixed::from_f64(x).sub(Fixed::from_f64(k).mul(LN2_FIXED));
+    exp_fixed(r).round_to_f64(k as i32)
+}
+
+/// Computes ln(x) correctly rounded to the nearest f64.
+///
+/// The result comes from `ln_dd()` unless it lies too close to a rounding boundary, in
+/// which case it is refined by a Newton step in 192-bit fixed-point arithmetic.
+///
+/// Returns NaN for negative inputs and negative infinity for zero.
+pub const fn ln_cr(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x == f64::INFINITY {
+        return x;
+    }
+
+    if let Some(result) = round_if_unambiguous(ln_dd(DoubleDouble::from_f64(x))) {
+        return result;
+    }
+
+    // ln(x) = k·ln(2) + ln(m) with √½ ≤ m < √2
+    let mut k = binary_exponent(x);
+    let mut m = scale_by_power_of_two(x, -k);
+    if m > core::f64::consts::SQRT_2 {
+        m *= 0.5;
+        k += 1;
+    }
+
+    // y ← y + m·e^(-y) - 1, starting from the double-double logarithm
+    let y = Fixed::from_dd(ln_dd(DoubleDouble::from_f64(m)));
+    let y = y
+        .add(Fixed::from_f64(m).mul(exp_fixed(y.neg())))
+        .sub(Fixed::ONE);
+
+    Fixed::from_f64(k as f64)
+        .mul(LN2_FIXED)
+        .add(y)
+        .round_to_f64(0)
+}
+
+/// Computes √x correctly rounded to the nearest f64.
+///
+/// The result comes from `sqrt_dd()` unless it lies too close to a rounding boundary, in
+/// which case the nearest f64 is found by comparing `x` with the exact squares of the
+/// rounding midpoints.
+///
+/// Returns NaN for negative inputs.
+pub const fn sqrt_cr(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 || x == f64::INFINITY {
+        return x;
+    }
+
+    if let Some(result) = round_if_unambiguous(sqrt_dd(DoubleDouble::from_f64(x))) {
+        return result;
+    }
+
+    // √x = √m · 2^k with 1 ≤ m < 4, so that √m lies in [1, 2)
+    let k = binary_exponent(x).div_euclid(2);
+    let m = scale_by_power_of_two(x, -2 * k);
+    let m_fixed = Fixed::from_f64(m);
+    let half_ulp = Fixed::from_f64(HALF_ULP_OF_ONE);
+
+    let mut root = sqrt_dd(DoubleDouble::from_f64(m)).hi();
+    loop {
+        let above = Fixed::from_f64(root).add(half_ulp);
+        let below = Fixed::from_f64(root).sub(half_ulp);
+
+        if above.mul(above).is_less_than(m_fixed) {
+            root = f64::from_bits(root.to_bits() + 1);
+        } else if root > 1.0 && m_fixed.is_less_than(below.mul(below)) {
+            root = f64::from_bits(root.to_bits() - 1);
+        } else {
+            break;
+        }
+    }
+    scale_by_power_of_two(root, k)
+}
+
+/// Computes `sin(x + quarter_turns·π/2)` for `|x| ≥ SIN_COS_TINY`.
+const fn sin_quadrant_cr(x: f64, quarter_turns: usize) -> f64 {
+    let (r, quadrant) = if abs(x) < core::f64::consts::FRAC_PI_4 {
+        (Fixed::from_f64(abs(x)), 0)
+    } else {
+        reduce_half_pi(abs(x))
+    };
+
+    // sin(-x) = -sin(x), while cos(-x) = cos(x)
+    let quadrant = (quadrant + quarter_turns) % 4;
+    let negative = (quadrant >= 2) != (x < 0.0 && quarter_turns.is_multiple_of(2));
+    let odd = quadrant.is_multiple_of(2);
+
+    let r_dd = r.to_dd();
+    let value = if odd { sin_dd(r_dd) } else { cos_dd(r_dd) };
+    let value = if negative { value.neg() } else { value };
+    if let Some(result) = round_if_unambiguous(value) {
+        return result;
+    }
+
+    let value = sin_cos_fixed(r, odd);
+    let value = if negative { value.neg() } else { value };
+    value.round_to_f64(0)
+}
+
+/// Rounds `value` to the nearest f64 if its whole error interval rounds the same way.
+const fn round_if_unambiguous(value: DoubleDouble) -> Option<f64> {
+    let bound = abs(value.hi()) * FAST_PATH_RELATIVE_ERROR;
+    let low = value.hi() + (value.lo() - bound);
+    let high = value.hi() + (value.lo() + bound);
+
+    if low == high { Some(low) } else { None }
+}
+
+/// Multiplies `x` by `2^k` in two steps, so that the result is exact whenever it is
+/// representable, even if it or `2^k` is subnormal.
+const fn scale_by_power_of_two(x: f64, k: i32) -> f64 {
+    x * static_powi(2.0, k / 2) * static_powi(2.0, k - k / 2)
+}
+
+/// Reduces a finite `x ≥ π/4` to `r = x - k·π/2` with `|r| ≤ π/4`, returning `r` and
+/// `k mod 4` (Payne–Hanek reduction).
+///
+/// With `x = M·2^e` for an integer significand `M`, only the bits of 2/π from position
+/// `e - 2` onwards affect `x·2/π` modulo 4. A window of them is multiplied exactly with `M`.
+const fn reduce_half_pi(x: f64) -> (Fixed, usize) {
+    let bits = x.to_bits();
+    let significand = (bits & ((1 << 52) - 1)) | (1 << 52);
+    let e = ((bits >> 52) & 0x7ff) as i32 - 1075;
+    let skip = if e > 2 { e - 2 } else { 0 };
+
+    // window = the REDUCTION_WINDOW_WORDS words of 2/π after the first `skip` bits,
+    // least significant word first
+    let mut window = [0u64; REDUCTION_WINDOW_WORDS];
+    let mut i = 0;
+    while i < REDUCTION_WINDOW_WORDS {
+        window[REDUCTION_WINDOW_WORDS - 1 - i] = two_over_pi_word(skip as usize + 64 * i);
+        i += 1;
+    }
+
+    // x·2/π ≡ product · 2^-point (mod 4)
+    let mut product = [0u64; REDUCTION_WINDOW_WORDS + 1];
+    let mut carry: u128 = 0;
+    let mut i = 0;
+    while i < REDUCTION_WINDOW_WORDS {
+        let t = window[i] as u128 * significand as u128 + carry;
+        product[i] = t as u64;
+        carry = t >> 64;
+        i += 1;
+    }
+    product[REDUCTION_WINDOW_WORDS] = carry as u64;
+    let point = (64 * REDUCTION_WINDOW_WORDS) as i32 + skip - e;
+
+    let mut quadrant = (word_at(&product, point) & 3) as usize;
+    let mut fraction = Fixed::from_magnitude([
+        word_at(&product, point - 192),
+        word_at(&product, point - 128),
+        word_at(&product, point - 64),
+        0,
+    ]);
+
+    // Round to the nearest quadrant, so that the fraction lies in [-1/2, 1/2]
+    if fraction.magnitude[2] >> 63 == 1 {
+        quadrant = (quadrant + 1) % 4;
+        fraction = fraction.sub(Fixed::ONE);
+    }
+    (fraction.mul(HALF_PI_FIXED), quadrant)
+}
+
+/// Reduces a finite double-double `x` to `r = x - k·π/2` with `|r| ≤ π/4`, returning `r`
+/// and `k mod 4`.
+///
+/// Both parts are reduced exactly with `reduce_half_pi()`, so this keeps full precision for
+/// any magnitude. `sin_dd()` and `cos_dd()` use it beyond the range of their three-part π/2.
+pub(crate) const fn reduce_half_pi_dd(x: DoubleDouble) -> (DoubleDouble, usize) {
+    let (r_hi, quadrant_hi) = reduce_half_pi_signed(x.hi());
+    let (r_lo, quadrant_lo) = reduce_half_pi_signed(x.lo());
+    let mut r = r_hi.add(r_lo);
+    let mut quadrant = (quadrant_hi + quadrant_lo) % 4;
+
+    // The sum lies in [-π/2, π/2]; move it back into [-π/4, π/4]
+    if HALF_PI_FIXED.is_less_than(r.add(r)) {
+        r = r.sub(HALF_PI_FIXED);
+        quadrant = (quadrant + 1) % 4;
+    } else if r.add(r).is_less_than(HALF_PI_FIXED.neg()) {
+        r = r.add(HALF_PI_FIXED);
+        quadrant = (quadrant + 3) % 4;
+    }
+    (r.to_dd(), quadrant)
+}
+
+/// Reduces a finite `x` of either sign like `reduce_half_pi()`, leaving `|x| < π/4` as is.
+const fn reduce_half_pi_signed(x: f64) -> (Fixed, usize) {
+    if abs(x) < core::f64::consts::FRAC_PI_4 {
+        return (Fixed::from_f64(x), 0);
+    }
+
+    // x = -(k·π/2 + r) = -k·π/2 - r for negative x
+    let (r, quadrant) = reduce_half_pi(abs(x));
+    if x < 0.0 {
+        (r.neg(), (4 - quadrant) % 4)
+    } else {
+        (r, quadrant)
+    }
+}
+
+/// Returns the 64 bits of 2/π after its first `skip` bits.
+const fn two_over_pi_word(skip: usize) -> u64 {
+    let index = skip / 64;
+    let offset = skip % 64;
+    let high = if index < TWO_OVER_PI_BITS.len() {
+        TWO_OVER_PI_BITS[index]
+    } else {
+        0
+    };
+    let low = if index + 1 < TWO_OVER_PI_BITS.len() {
+        TWO_OVER_PI_BITS[index + 1]
+    } else {
+        0
+    };
+
+    if offset == 0 {
+        high
+    } else {
+        (high << offset) | (low >> (64 - offset))
+    }
+}
+
+/// Returns bits `low .. low + 64` of the number whose words are `words`, least significant
+/// word first. Bits outside the words read as zero.
+const fn word_at(words: &[u64], low: i32) -> u64 {
+    if low <= -64 || low >= 64 * words.len() as i32 {
+        return 0;
+    }
+    if low < 0 {
+        return words[0] << -low;
+    }
+
+    let index = low as usize / 64;
+    let offset = low as usize % 64;
+    let next = if index + 1 < words.len() {
+        words[index + 1]
+    } else {
+        0
+    };
+
+    if offset == 0 {
+        words[index]
+    } else {
+        (words[index] >> offset) | (next << (64 - offset))
+    }
+}
+
+/// Sums the Taylor series of `e^r` in fixed point, for `|r| ≤ 1/2`.
+const fn exp_fixed(r: Fixed) -> Fixed {
+    let mut sum = Fixed::ONE;
+    let mut term = Fixed::ONE;
+    let mut n = 1;
+
+    while !term.is_zero() {
+        term = term.mul(r).div_small(n);
+        sum = sum.add(term);
+        n += 1;
+    }
+    sum
+}
+
+/// Sums the Taylor series of `sin(r)` (`odd`) or `cos(r)` in fixed point, for `|r| ≤ π/4`.
+const fn sin_cos_fixed(r: Fixed, odd: bool) -> Fixed {
+    let r2 = r.mul(r);
+    let mut term = if odd { r } else { Fixed::ONE };
+    let mut sum = term;
+    let mut n: u64 = if odd { 1 } else { 0 };
+
+    while !term.is_zero() {
+        term = term.mul(r2).div_small((n + 1) * (n + 2)).neg();
+        sum = sum.add(term);
+        n += 2;
+    }
+    sum
+}
+
+/// A signed fixed-point number with 64 integer and 192 fractional bits, used where the
+/// double-double kernels cannot decide the rounding.
+///
+/// `magnitude` holds the absolute value times 2^192, least significant word first. Products
+/// and quotients are truncated to 192 fractional bits.
+#[derive(Clone, Copy)]
+struct Fixed {
+    negative: bool,
+    magnitude: [u64; 4],
+}
+
+impl Fixed {
+    const ONE: Self = Self::from_magnitude([0, 0, 0, 1]);
+
+    const fn from_magnitude(magnitude: [u64; 4]) -> Self {
+        Self {
+            negative: false,
+            magnitude,
+        }
+    }
+
+    /// Converts `x`, which must be below 2^63 in magnitude. Bits below 2^-192 are dropped.
+    const fn from_f64(x: f64) -> Self {
+        let bits = x.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let (significand, e) = if biased_exponent == 0 {
+            (bits & ((1 << 52) - 1), -1074)
+        } else {
+            ((bits & ((1 << 52) - 1)) | (1 << 52), biased_exponent - 1075)
+        };
+
+        // magnitude = significand · 2^(e + FRACTION_BITS)
+        let shift = e + FRACTION_BITS;
+        let mut magnitude = [0u64; 4];
+        if shift >= 0 {
+            let index = shift as usize / 64;
+            let offset = shift as usize % 64;
+            magnitude[index] = significand << offset;
+            if offset > 0 && index + 1 < 4 {
+                magnitude[index + 1] = significand >> (64 - offset);
+            }
+        } else if shift > -64 {
+            magnitude[0] = significand >> -shift;
+        }
+
+        Self {
+            negative: x < 0.0,
+            magnitude,
+        }
+    }
+
+    const fn from_dd(x: DoubleDouble) -> Self {
+        Self::from_f64(x.hi()).add(Self::from_f64(x.lo()))
+    }
+
+    /// Splits the value into a double-double, accurate to about 2^-106 relative.
+    const fn to_dd(self) -> DoubleDouble {
+        let hi = self.round_to_f64(0);
+        let lo = self.sub(Self::from_f64(hi)).round_to_f64(0);
+        DoubleDouble::new(hi, lo)
+    }
+
+    const fn is_zero(&self) -> bool {
+        let m = self.magnitude;
+        m[0] == 0 && m[1] == 0 && m[2] == 0 && m[3] == 0
+    }
+
+    const fn is_less_than(self, other: Self) -> bool {
+        let difference = self.sub(other);
+        difference.negative && !difference.is_zero()
+    }
+
+    const fn neg(self) -> Self {
+        Self {
+            negative: !self.negative,
+            magnitude: self.magnitude,
+        }
+    }
+
+    const fn add(self, other: Self) -> Self {
+        if self.negative == other.negative {
+            return Self {
+                negative: self.negative,
+                magnitude: add_magnitudes(self.magnitude, other.magnitude),
+            };
+        }
+
+        if compare_magnitudes(self.magnitude, other.magnitude) >= 0 {
+            Self {
+                negative: self.negative,
+                magnitude: sub_magnitudes(self.magnitude, other.magnitude),
+            }
+        } else {
+            Self {
+                negative: other.negative,
+                magnitude: sub_magnitudes(other.magnitude, self.magnitude),
+            }
+        }
+    }
+
+    const fn sub(self, other: Self) -> Self {
+        self.add(other.neg())
+    }
+
+    const fn mul(self, other: Self) -> Self {
+        let a = self.magnitude;
+        let b = other.magnitude;
+        let mut product = [0u64; 8];
+        let mut i = 0;
+
+        while i < 4 {
+            let mut carry: u128 = 0;
+            let mut j = 0;
+            while j < 4 {
+                let t = a[i] as u128 * b[j] as u128 + product[i + j] as u128 + carry;
+                product[i + j] = t as u64;
+                carry = t >> 64;
+                j += 1;
+            }
+            product[i + 4] = carry as u64;
+            i += 1;
+        }
+        assert!(product[7] == 0, "fixed-point product overflows");
+
+        Self {
+            negative: self.negative != other.negative,
+            magnitude: [product[3], product[4], product[5], product[6]],
+        }
+    }
+
+    const fn div_small(self, divisor: u64) -> Self {
+        let mut magnitude = [0u64; 4];
+        let mut remainder: u128 = 0;
+        let mut i = 4;
+
+        while i > 0 {
+            i -= 1;
+            let current = (remainder << 64) | self.magnitude[i] as u128;
+            magnitude[i] = (current / divisor as u128) as u64;
+            remainder = current % divisor as u128;
+        }
+
+        Self {
+            negative: self.negative,
+            magnitude,
+        }
+    }
+
+    /// Rounds `self · 2^scale` to the nearest f64, ties to even, including subnormal and
+    /// infinite results.
+    const fn round_to_f64(self, scale: i32) -> f64 {
+        let m = self.magnitude;
+        let mut top = 255;
+        while top >= 0 && !bit(&m, top) {
+            top -= 1;
+        }
+        if top < 0 {
+            return if self.negative { -0.0 } else { 0.0 };
+        }
+
+        // The value lies in [2^exponent, 2^(exponent + 1)); subnormals keep fewer bits
+        let exponent = top - FRACTION_BITS + scale;
+        let precision = if exponent < -1022 {
+            exponent + 1075
+        } else {
+            53
+        };
+        let low = top - precision + 1;
+
+        let mut significand = if low > top { 0 } else { word_at(&m, low) };
+        let round_bit = bit(&m, low - 1);
+        let sticky = any_bit_below(&m, low - 1);
+        if round_bit && (sticky || significand & 1 == 1) {
+            significand += 1;
+        }
+
+        let result = scale_by_power_of_two(significand as f64, low - FRACTION_BITS + scale);
+        if self.negative { -result } else { result }
+    }
+}
+
+/// Returns bit `index` of `words`, least significant word first.
+const fn bit(words: &[u64], index: i32) -> bool {
+    if index < 0 || index >= 64 * words.len() as i32 {
+        return false;
+    }
+    (words[index as usize / 64] >> (index as usize % 64)) & 1 == 1
+}
+
+/// Returns whether any of the bits below `index` of `words` is set.
+const fn any_bit_below(words: &[u64], index: i32) -> bool {
+    let mut i = 0;
+    while i < words.len() as i32 && 64 * i < index {
+        let bits_in_word = index - 64 * i;
+        let mask = if bits_in_word >= 64 {
+            u64::MAX
+        } else {
+            (1 << bits_in_word) - 1
+        };
+        if words[i as usize] & mask != 0 {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns `-1`, `0` or `1` as `a` is less than, equal to or greater than `b`.
+const fn compare_magnitudes(a: [u64; 4], b: [u64; 4]) -> i32 {
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return if a[i] < b[i] { -1 } else { 1 };
+        }
+    }
+    0
+}
+
+const fn add_magnitudes(a: [u64; 4], b: [u64; 4]) -> [u64; 4] {
+    let mut sum = [0u64; 4];
+    let mut carry = false;
+    let mut i = 0;
+
+    while i < 4 {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        sum[i] = s;
+        carry = c1 || c2;
+        i += 1;
+    }
+    assert!(!carry, "fixed-point sum overflows");
+    sum
+}
+
+/// Computes `a - b`, assuming `a ≥ b`.
+const fn sub_magnitudes(a: [u64; 4], b: [u64; 4]) -> [u64; 4] {
+    let mut difference = [0u64; 4];
+    let mut borrow = false;
+    let mut i = 0;
+
+    while i < 4 {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        difference[i] = d;
+        borrow = b1 || b2;
+        i += 1;
+    }
+    difference
+}
@@ -0,0 +1,211 @@
+use crate::term::{Term, VarFunction};
+
+/// Represents an ordinary single-variable polynomial of degree DEG, stored as its
+/// `DEG + 1` coefficients in ascending order: `c_0 + c_1·x + ... + c_DEG·x^DEG`.
+///
+/// Evaluation uses Horner's rule by default, which needs one multiplication and one addition
+/// per coefficient and is more accurate than summing separately computed powers. Estrin's
+/// scheme can be selected instead with `with_scheme`, see `Scheme`.
+///
+/// # Example
+///
+/// ```
+/// use const_poly::DensePoly;
+///
+/// // p(x) = 1 - 3x + 2x³
+/// const P: DensePoly<3> = DensePoly::new(&[1.0, -3.0, 0.0, 2.0]);
+///
+/// const VAL: f64 = P.evaluate(2.0);
+/// assert_eq!(VAL, 11.0);
+/// ```
+#[derive(Clone, Copy)]
+pub struct DensePoly<const DEG: usize> {
+    coeffs: &'static [f64],
+    scheme: Scheme,
+}
+
+/// Enum selecting how a `DensePoly` is evaluated.
+///
+/// Variants:
+/// - `Horner`: Horner's rule, a single chain of `DEG` multiply-adds
+/// - `Estrin`: Estrin's scheme, which combines pairs of coefficients, then pairs of pairs,
+///   and so on. It does a few more multiplications than Horner, but the multiply-adds on
+///   each level are independent, so the dependency chain is only about `log2(DEG)` steps
+///   long and high-degree polynomials evaluate faster on superscalar CPUs.
+#[derive(Clone, Copy)]
+pub enum Scheme {
+    Horner,
+    Estrin,
+}
+
+impl<const DEG: usize> DensePoly<DEG> {
+    /// Creates a new `DensePoly` from its coefficients, constant coefficient first.
+    ///
+    /// Panics (at compile time, in a const context) if `coeffs` does not hold exactly
+    /// `DEG + 1` coefficients.
+    ///
+    /// # Parameters
+    ///
+    /// - `coeffs`: The coefficients `c_0, c_1, ..., c_DEG`.
+    ///
+    /// # Returns
+    ///
+    /// A new `DensePoly` instance.
+    pub const fn new(coeffs: &'static [f64]) -> Self {
+        Self::with_scheme(coeffs, Scheme::Horner)
+    }
+
+    /// Creates a new `DensePoly` from its coefficients, evaluated with the given scheme.
+    ///
+    /// Panics (at compile time, in a const context) if `coeffs` does not hold exactly
+    /// `DEG + 1` coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::dense::{DensePoly, Scheme};
+    ///
+    /// // p(x) = 1 + x + x² + ... + x⁷
+    /// const P: DensePoly<7> = DensePoly::with_scheme(&[1.0; 8], Scheme::Estrin);
+    ///
+    /// assert_eq!(P.evaluate(2.0), 255.0);
+    /// ```
+    pub const fn with_scheme(coeffs: &'static [f64], scheme: Scheme) -> Self {
+        assert!(
+            coeffs.len() == DEG + 1,
+            "a DensePoly<DEG> needs exactly DEG + 1 coefficients"
+        );
+        Self { coeffs, scheme }
+    }
+
+    /// Returns the coefficients of the polynomial, constant coefficient first.
+    pub const fn coeffs(&self) -> &'static [f64] {
+        self.coeffs
+    }
+
+    /// Returns the evaluation scheme of the polynomial.
+    pub const fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Evaluates the polynomial at `x` using its evaluation scheme.
+    ///
+    /// # Parameters
+    ///
+    /// - `x`: The value of the variable.
+    ///
+    /// # Returns
+    ///
+    /// The floating-point result of evaluating the polynomial.
+    pub const fn evaluate(&self, x: f64) -> f64 {
+        match self.scheme {
+            Scheme::Horner => self.evaluate_horner(x),
+            Scheme::Estrin => self.evaluate_estrin(x),
+        }
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's rule.
+    pub const fn evaluate_horner(&self, x: f64) -> f64 {
+        let mut result = self.coeffs[DEG];
+        let mut k = DEG;
+
+        while k > 0 {
+            k -= 1;
+            result = result * x + self.coeffs[k];
+        }
+        result
+    }
+
+    /// Evaluates the polynomial at `x` using Estrin's scheme.
+    ///
+    /// Coefficients are merged in blocks of 1, 2, 4, ... like the digits of a binary counter:
+    /// two adjacent blocks of `2^j` coefficients combine into `low + x^(2^j) · high`.
+    pub const fn evaluate_estrin(&self, x: f64) -> f64 {
+        // powers[j] = x^(2^j)
+        let mut powers = [x; usize::BITS as usize];
+        let mut j = 1;
+        while (1 << j) <= DEG {
+            powers[j] = powers[j - 1] * powers[j - 1];
+            j += 1;
+        }
+
+        // Pending blocks, lowest coefficients first, with strictly decreasing levels
+        let mut values = [0.0; usize::BITS as usize];
+        let mut levels = [0; usize::BITS as usize];
+        let mut len = 0;
+        let mut k = 0;
+
+        while k <= DEG {
+            values[len] = self.coeffs[k];
+            levels[len] = 0;
+            len += 1;
+
+            while len >= 2 && levels[len - 2] == levels[len - 1] {
+                let level = levels[len - 1];
+                values[len - 2] += powers[level] * values[len - 1];
+                levels[len - 2] = level + 1;
+                len -= 1;
+            }
+            k += 1;
+        }
+
+        // Fold the remaining blocks, highest coefficients first
+        let mut result = values[len - 1];
+        while len > 1 {
+            len -= 1;
+            result = values[len - 1] + powers[levels[len - 1]] * result;
+        }
+        result
+    }
+
+    /// Returns the number of nonzero coefficients, i.e. the number of terms written by
+    /// `to_terms`.
+    pub const fn num_terms(&self) -> usize {
+        let mut count = 0;
+        let mut k = 0;
+
+        while k <= DEG {
+            if self.coeffs[k] != 0.0 {
+                count += 1;
+            }
+            k += 1;
+        }
+        count
+    }
+
+    /// Converts the polynomial into the terms of a `Polynomial<1>`, one `Pow(k)` term per
+    /// nonzero coefficient `c_k`.
+    ///
+    /// Panics (at compile time, in a const context) if `LEN` differs from `num_terms()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::{DensePoly, Polynomial, Term};
+    ///
+    /// const P: DensePoly<2> = DensePoly::new(&[1.0, 0.0, 3.0]);
+    /// const TERMS: [Term<1>; P.num_terms()] = P.to_terms();
+    /// const POLY: Polynomial<1> = Polynomial::new(&TERMS);
+    ///
+    /// assert_eq!(POLY.evaluate_scalar(2.0), P.evaluate(2.0));
+    /// ```
+    pub const fn to_terms<const LEN: usize>(&self) -> [Term<1>; LEN] {
+        assert!(
+            LEN == self.num_terms(),
+            "array length must equal num_terms()"
+        );
+
+        let mut terms = [Term::new(0.0, [VarFunction::Pow(0)]); LEN];
+        let mut t = 0;
+        let mut k = 0;
+
+        while k <= DEG {
+            if self.coeffs[k] != 0.0 {
+                terms[t] = Term::new(self.coeffs[k], [VarFunction::Pow(k as i32)]);
+                t += 1;
+            }
+            k += 1;
+        }
+        terms
+    }
+}
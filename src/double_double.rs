@@ -0,0 +1,548 @@
+use crate::correctly_rounded::reduce_half_pi_dd;
+use crate::function_approximations::*;
+
+// === Internal configuration constants ===
+
+/// π/2 split into three f64 parts, for argument reduction beyond double-double precision.
+const HALF_PI_PARTS: [f64; 3] = [
+    core::f64::consts::FRAC_PI_2,
+    6.123233995736766e-17,
+    -1.4973849048591698e-33,
+];
+
+/// Magnitude above which `sin_dd()` and `cos_dd()` switch from the three-part π/2 to
+/// Payne–Hanek argument reduction.
+const SIN_COS_DD_REDUCTION_LIMIT: f64 = 1e15;
+
+/// 2^-54: below `|k|` times this, `x - k·π/2` cancels too much for the three-part π/2, whose
+/// error grows with `k`, and `sin_dd()` and `cos_dd()` fall back to Payne–Hanek reduction.
+const SIN_COS_DD_CANCELLATION_RATIO: f64 = 5.551115123125783e-17;
+
+/// Magnitude above which e^(-|x|) is negligible next to e^|x| in double-double, so that
+/// `sinh_dd()` and `cosh_dd()` reduce to e^|x| / 2.
+const SINH_DD_LARGE_CUTOFF: f64 = 40.0;
+
+/// π/2 as a double-double.
+const HALF_PI_DD: DoubleDouble = DoubleDouble::new(HALF_PI_PARTS[0], HALF_PI_PARTS[1]);
+
+/// ln(2) split into three f64 parts, for argument reduction beyond double-double precision.
+const LN2_PARTS: [f64; 3] = [
+    core::f64::consts::LN_2,
+    2.3190468138462996e-17,
+    5.707708438416212e-34,
+];
+
+/// 2^54, used to bring subnormal numbers into the normal range.
+const TWO_POW_54: f64 = 18014398509481984.0;
+
+/// Arguments above which `exp_dd()` overflows, and below which it underflows to zero.
+const EXP_DD_OVERFLOW_CUTOFF: f64 = 709.782712893384;
+const EXP_DD_UNDERFLOW_CUTOFF: f64 = -745.1332191019412;
+
+/// `exp_dd()` evaluates its Taylor series at `r / 2^EXP_DD_HALVINGS`, then squares back.
+const EXP_DD_HALVINGS: usize = 10;
+
+// Taylor expansion term counts for the double-double kernels
+const EXP_DD_TAYLOR_TERMS: usize = 12;
+const SIN_COS_DD_TAYLOR_TERMS: usize = 18;
+const SINH_DD_TAYLOR_TERMS: usize = 20;
+const LN_DD_SERIES_TERMS: usize = 24;
+
+// Newton iteration counts, seeded from the f64 approximations
+const SQRT_DD_NEWTON_ITERATIONS: usize = 3;
+const ARCTAN_DD_NEWTON_ITERATIONS: usize = 2;
+
+// ========================================
+
+/// A double-double number: the unevaluated sum `hi + lo` of two f64 values with
+/// `|lo| ≤ ulp(hi) / 2`, giving about 106 bits (32 decimal digits) of precision.
+///
+/// All arithmetic is `const`, so intermediate steps of compile-time computations can be
+/// carried out beyond f64 precision and rounded once at the end with `to_f64`. The range is
+/// that of f64, but precision degrades for magnitudes below about 1e-290, where the error
+/// terms become subnormal.
+///
+/// # Example
+///
+/// ```
+/// use const_poly::DoubleDouble;
+///
+/// // 0.1 + 0.2 - 0.3 is 5.55e-17 in f64, but the decimal inputs are not representable:
+/// // the exact sum of the three f64 values is recovered
+/// const X: DoubleDouble = DoubleDouble::from_f64(0.1)
+///     .add(DoubleDouble::from_f64(0.2))
+///     .sub(DoubleDouble::from_f64(0.3));
+///
+/// assert_eq!(X.to_f64(), 2.7755575615628914e-17);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+// The arithmetic methods mirror `core::ops`, whose traits cannot be used in const fns
+#[allow(clippy::should_implement_trait)]
+impl DoubleDouble {
+    /// Creates a new `DoubleDouble` equal to `hi + lo`, renormalizing the two parts.
+    pub const fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+        Self::normalized(hi, lo)
+    }
+
+    /// Creates a new `DoubleDouble` equal to `x`.
+    pub const fn from_f64(x: f64) -> Self {
+        Self { hi: x, lo: 0.0 }
+    }
+
+    /// Returns the leading part, which is `self` rounded to f64.
+    pub const fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Returns the trailing part, the rounding error of `hi`.
+    pub const fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Rounds the number to the nearest f64.
+    pub const fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+
+    /// Returns `-self`.
+    pub const fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+
+    /// Returns `self + other`.
+    pub const fn add(self, other: Self) -> Self {
+        let (s, e) = two_sum(self.hi, other.hi);
+        if !s.is_finite() {
+            return Self::from_f64(s);
+        }
+
+        let (t, f) = two_sum(self.lo, other.lo);
+        let (s, e) = quick_two_sum(s, e + t);
+        let (s, e) = quick_two_sum(s, e + f);
+        Self::normalized(s, e)
+    }
+
+    /// Returns `self - other`.
+    pub const fn sub(self, other: Self) -> Self {
+        self.add(other.neg())
+    }
+
+    /// Returns `self * other`.
+    pub const fn mul(self, other: Self) -> Self {
+        let (p, e) = two_product(self.hi, other.hi);
+        if !p.is_finite() {
+            return Self::from_f64(p);
+        }
+
+        let (p, e) = quick_two_sum(p, e + (self.hi * other.lo + self.lo * other.hi));
+        Self::normalized(p, e)
+    }
+
+    /// Returns `self * x` for an f64 `x`.
+    pub const fn mul_f64(self, x: f64) -> Self {
+        self.mul(Self::from_f64(x))
+    }
+
+    /// Returns `self / other`.
+    pub const fn div(self, other: Self) -> Self {
+        let q1 = self.hi / other.hi;
+        if !q1.is_finite() || q1 == 0.0 {
+            return Self::from_f64(q1);
+        }
+
+        // Long division, one f64 digit at a time
+        let r = self.sub(other.mul_f64(q1));
+        let q2 = r.hi / other.hi;
+        let r = r.sub(other.mul_f64(q2));
+        let q3 = r.hi / other.hi;
+
+        let (q1, q2) = quick_two_sum(q1, q2);
+        Self::normalized(q1, q2).add(Self::from_f64(q3))
+    }
+
+    /// Returns `self / x` for an f64 `x`.
+    pub const fn div_f64(self, x: f64) -> Self {
+        self.div(Self::from_f64(x))
+    }
+
+    /// Keeps `hi` alone when it is not finite, where the error term would be NaN.
+    const fn normalized(hi: f64, lo: f64) -> Self {
+        if hi.is_finite() {
+            Self { hi, lo }
+        } else {
+            Self { hi, lo: 0.0 }
+        }
+    }
+}
+
+impl core::ops::Add for DoubleDouble {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        DoubleDouble::add(self, other)
+    }
+}
+
+impl core::ops::Sub for DoubleDouble {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        DoubleDouble::sub(self, other)
+    }
+}
+
+impl core::ops::Mul for DoubleDouble {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        DoubleDouble::mul(self, other)
+    }
+}
+
+impl core::ops::Div for DoubleDouble {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        DoubleDouble::div(self, other)
+    }
+}
+
+impl core::ops::Neg for DoubleDouble {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        DoubleDouble::neg(self)
+    }
+}
+
+/// Computes `a + b` and its rounding error, assuming `|a| ≥ |b|` or `a == 0`.
+const fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// Multiplies `x` by `2^k` exactly, in two steps so that neither scale factor overflows.
+const fn scale_by_power_of_two(x: DoubleDouble, k: i32) -> DoubleDouble {
+    let first = static_powi(2.0, k / 2);
+    let second = static_powi(2.0, k - k / 2);
+    DoubleDouble {
+        hi: x.hi * first * second,
+        lo: x.lo * first * second,
+    }
+}
+
+/// Returns `x - k·c` for the constant `c = parts[0] + parts[1] + parts[2]`.
+///
+/// Each product `k·parts[i]` is exact as a double-double, so only the final subtractions
+/// round.
+const fn sub_multiple(x: DoubleDouble, k: f64, parts: &[f64; 3]) -> DoubleDouble {
+    let mut result = x;
+    let mut i = 0;
+
+    while i < parts.len() {
+        let (p, e) = two_product(k, parts[i]);
+        result = result.sub(DoubleDouble::new(p, e));
+        i += 1;
+    }
+    result
+}
+
+/// Returns the exponent `e` of a positive, finite `x = m·2^e` with `1 ≤ m < 2`.
+pub(crate) const fn binary_exponent(x: f64) -> i32 {
+    if x < f64::MIN_POSITIVE {
+        return binary_exponent(x * TWO_POW_54) - 54;
+    }
+    ((x.to_bits() >> 52) & 0x7ff) as i32 - 1023
+}
+
+/// Raises `x` to the integer power `exp` by binary exponentiation in double-double.
+pub const fn powi_dd(mut x: DoubleDouble, exp: i32) -> DoubleDouble {
+    let mut result = DoubleDouble::from_f64(1.0);
+    let mut positive_exp = exp.unsigned_abs();
+
+    while positive_exp > 0 {
+        if (positive_exp & 1) == 1 {
+            result = result.mul(x);
+        }
+        x = x.mul(x);
+        positive_exp >>= 1;
+    }
+
+    if exp < 0 {
+        DoubleDouble::from_f64(1.0).div(result)
+    } else {
+        result
+    }
+}
+
+/// Computes the square root of `x` by Newton's method in double-double, seeded with
+/// `sqrt_newton()` after splitting off an even power of two.
+///
+/// Returns NaN for negative inputs.
+pub const fn sqrt_dd(x: DoubleDouble) -> DoubleDouble {
+    if x.hi < 0.0 || x.hi.is_nan() {
+        return DoubleDouble::from_f64(f64::NAN);
+    }
+    if x.hi == 0.0 || x.hi == f64::INFINITY {
+        return x;
+    }
+
+    // x = m·4^k with 1 ≤ m < 4
+    let k = binary_exponent(x.hi).div_euclid(2);
+    let m = scale_by_power_of_two(x, -2 * k);
+
+    let mut y = DoubleDouble::from_f64(sqrt_newton(m.hi));
+    let mut i = 0;
+    while i < SQRT_DD_NEWTON_ITERATIONS {
+        y = y.add(m.div(y)).mul_f64(0.5);
+        i += 1;
+    }
+    scale_by_power_of_two(y, k)
+}
+
+/// Computes `e^x` in double-double.
+///
+/// The argument is reduced to `x = k·ln(2) + r`, and `e^r - 1` is summed as a Taylor
+/// series at `r / 2^10`, then squared back up.
+pub const fn exp_dd(x: DoubleDouble) -> DoubleDouble {
+    if x.hi.is_nan() {
+        return x;
+    }
+    if x.hi > EXP_DD_OVERFLOW_CUTOFF {
+        return DoubleDouble::from_f64(f64::INFINITY);
+    }
+    if x.hi < EXP_DD_UNDERFLOW_CUTOFF {
+        return DoubleDouble::from_f64(0.0);
+    }
+
+    let k = round(x.hi / LN2_PARTS[0]);
+    let r = sub_multiple(x, k, &LN2_PARTS);
+    let r = scale_by_power_of_two(r, -(EXP_DD_HALVINGS as i32));
+
+    // e^r - 1 = r + r²/2! + r³/3! + ...
+    let mut term = r;
+    let mut sum = r;
+    let mut n = 2;
+    while n <= EXP_DD_TAYLOR_TERMS {
+        term = term.mul(r).div_f64(n as f64);
+        sum = sum.add(term);
+        n += 1;
+    }
+
+    // (e^r - 1)² + 2(e^r - 1) = e^(2r) - 1
+    let mut i = 0;
+    while i < EXP_DD_HALVINGS {
+        sum = sum.mul(sum.add(DoubleDouble::from_f64(2.0)));
+        i += 1;
+    }
+
+    scale_by_power_of_two(sum.add(DoubleDouble::from_f64(1.0)), k as i32)
+}
+
+/// Computes the natural logarithm of `x` in double-double.
+///
+/// With `x = m·2^k` and `√½ ≤ m < √2`, `ln(m) = 2·atanh((m - 1) / (m + 1))` is summed as a
+/// series, and `k·ln(2)` is added back.
+///
+/// Returns NaN for non-positive inputs.
+pub const fn ln_dd(x: DoubleDouble) -> DoubleDouble {
+    if x.hi <= 0.0 || x.hi.is_nan() {
+        return DoubleDouble::from_f64(f64::NAN);
+    }
+    if x.hi == f64::INFINITY {
+        return x;
+    }
+
+    let mut k = binary_exponent(x.hi);
+    let mut m = scale_by_power_of_two(x, -k);
+    if m.hi > core::f64::consts::SQRT_2 {
+        m = m.mul_f64(0.5);
+        k += 1;
+    }
+
+    // 2·(s + s³/3 + s⁵/5 + ...) with |s| ≤ 0.172
+    let one = DoubleDouble::from_f64(1.0);
+    let s = m.sub(one).div(m.add(one));
+    let s2 = s.mul(s);
+    let mut power = s;
+    let mut y = s;
+    let mut n = 1;
+    while n < LN_DD_SERIES_TERMS {
+        power = power.mul(s2);
+        y = y.add(power.div_f64((2 * n + 1) as f64));
+        n += 1;
+    }
+    let y = y.mul_f64(2.0);
+
+    sub_multiple(y, -(k as f64), &LN2_PARTS)
+}
+
+/// Reduces `x` to `r = x - k·π/2` with `|r| ≤ π/4`, returning `r` and `k mod 4`.
+const fn reduce_half_pi(x: DoubleDouble) -> (DoubleDouble, usize) {
+    if abs(x.hi) > SIN_COS_DD_REDUCTION_LIMIT {
+        return reduce_half_pi_dd(x);
+    }
+
+    let k = round(x.hi / HALF_PI_PARTS[0]);
+    let r = sub_multiple(x, k, &HALF_PI_PARTS);
+    if abs(r.hi) < abs(k) * SIN_COS_DD_CANCELLATION_RATIO {
+        return reduce_half_pi_dd(x);
+    }
+    (r, (k % 4.0 + 4.0) as usize % 4)
+}
+
+/// Sums the Taylor series of `sin(r)` (`odd`) or `cos(r)` for a reduced argument `r`.
+const fn sin_cos_kernel_dd(r: DoubleDouble, odd: bool) -> DoubleDouble {
+    let r2 = r.mul(r);
+    let mut term = if odd { r } else { DoubleDouble::from_f64(1.0) };
+    let mut sum = term;
+    let mut n = if odd { 2 } else { 1 };
+    let mut i = 1;
+
+    while i < SIN_COS_DD_TAYLOR_TERMS {
+        // term ← -term · r² / (n (n + 1))
+        term = term.mul(r2).div_f64((n * (n + 1)) as f64).neg();
+        sum = sum.add(term);
+        n += 2;
+        i += 1;
+    }
+    sum
+}
+
+/// Computes `sin(x)` in double-double.
+///
+/// The argument is reduced by a three-part π/2 for `|x|` up to 1e15, and exactly by
+/// Payne–Hanek reduction beyond or wherever `x` lies so close to a multiple of π/2 that the
+/// three-part reduction would cancel, so full precision is kept for any finite `x`.
+///
+/// Returns NaN for infinite or NaN inputs.
+pub const fn sin_dd(x: DoubleDouble) -> DoubleDouble {
+    if !x.hi.is_finite() {
+        return DoubleDouble::from_f64(f64::NAN);
+    }
+
+    let (r, quadrant) = reduce_half_pi(x);
+    match quadrant {
+        0 => sin_cos_kernel_dd(r, true),
+        1 => sin_cos_kernel_dd(r, false),
+        2 => sin_cos_kernel_dd(r, true).neg(),
+        _ => sin_cos_kernel_dd(r, false).neg(),
+    }
+}
+
+/// Computes `cos(x)` in double-double, reducing the argument like `sin_dd()`.
+///
+/// Returns NaN for infinite or NaN inputs.
+pub const fn cos_dd(x: DoubleDouble) -> DoubleDouble {
+    if !x.hi.is_finite() {
+        return DoubleDouble::from_f64(f64::NAN);
+    }
+
+    let (r, quadrant) = reduce_half_pi(x);
+    match quadrant {
+        0 => sin_cos_kernel_dd(r, false),
+        1 => sin_cos_kernel_dd(r, true).neg(),
+        2 => sin_cos_kernel_dd(r, false).neg(),
+        _ => sin_cos_kernel_dd(r, true),
+    }
+}
+
+/// Computes `tan(x)` as `sin(x) / cos(x)` in double-double.
+pub const fn tan_dd(x: DoubleDouble) -> DoubleDouble {
+    sin_dd(x).div(cos_dd(x))
+}
+
+/// Computes `arctan(x)` by Newton's method on `tan(y) = x` in double-double, seeded with
+/// `arctan_approx()`.
+///
+/// For `|x| > 1`, where the iteration converges poorly near the pole of tan, it is computed
+/// as `±π/2 - arctan(1/x)`.
+pub const fn arctan_dd(x: DoubleDouble) -> DoubleDouble {
+    if x.hi.is_nan() {
+        return x;
+    }
+    if abs(x.hi) > 1.0 {
+        let half_pi = if x.hi > 0.0 {
+            HALF_PI_DD
+        } else {
+            HALF_PI_DD.neg()
+        };
+        return half_pi.sub(arctan_dd(DoubleDouble::from_f64(1.0).div(x)));
+    }
+
+    // y ← y - (tan(y) - x)·cos²(y) = y - (sin(y) - x·cos(y))·cos(y)
+    let mut y = DoubleDouble::from_f64(arctan_approx(x.hi));
+    let mut i = 0;
+    while i < ARCTAN_DD_NEWTON_ITERATIONS {
+        let c = cos_dd(y);
+        y = y.sub(sin_dd(y).sub(x.mul(c)).mul(c));
+        i += 1;
+    }
+    y
+}
+
+/// Computes `sinh(x)` in double-double, by its Taylor series for `|x| < 1` and from
+/// `exp_dd()` otherwise.
+///
+/// For `|x| > 40` it is computed as `±e^(|x| - ln 2)`, which stays finite up to the
+/// overflow of sinh itself at about 710.5.
+pub const fn sinh_dd(x: DoubleDouble) -> DoubleDouble {
+    if x.hi.is_nan() || x.hi.is_infinite() {
+        return x;
+    }
+    if abs(x.hi) > SINH_DD_LARGE_CUTOFF {
+        let half_exp = exp_half(abs_dd(x));
+        return if x.hi < 0.0 { half_exp.neg() } else { half_exp };
+    }
+
+    if abs(x.hi) < 1.0 {
+        let x2 = x.mul(x);
+        let mut term = x;
+        let mut sum = x;
+        let mut n = 2;
+        let mut i = 1;
+
+        while i < SINH_DD_TAYLOR_TERMS {
+            term = term.mul(x2).div_f64((n * (n + 1)) as f64);
+            sum = sum.add(term);
+            n += 2;
+            i += 1;
+        }
+        return sum;
+    }
+
+    let e = exp_dd(x);
+    e.sub(DoubleDouble::from_f64(1.0).div(e)).mul_f64(0.5)
+}
+
+/// Computes `cosh(x)` from `exp_dd()` in double-double, as `e^(|x| - ln 2)` for `|x| > 40`.
+pub const fn cosh_dd(x: DoubleDouble) -> DoubleDouble {
+    if x.hi.is_nan() {
+        return x;
+    }
+    if abs(x.hi) > SINH_DD_LARGE_CUTOFF {
+        return exp_half(abs_dd(x));
+    }
+
+    let e = exp_dd(x);
+    e.add(DoubleDouble::from_f64(1.0).div(e)).mul_f64(0.5)
+}
+
+/// Returns `|x|`.
+const fn abs_dd(x: DoubleDouble) -> DoubleDouble {
+    if x.hi < 0.0 { x.neg() } else { x }
+}
+
+/// Computes `e^x / 2` as `e^(x - ln 2)`, which does not overflow before the result does.
+const fn exp_half(x: DoubleDouble) -> DoubleDouble {
+    exp_dd(sub_multiple(x, 1.0, &LN2_PARTS))
+}
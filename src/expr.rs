@@ -0,0 +1,145 @@
+use crate::function_approximations::*;
+use crate::term::VarFunction;
+
+/// Enum representing a single node of an expression tree.
+///
+/// Nodes live in a flat arena (`&'static [Node]`) and refer to their operands by index.
+/// Operands must appear before the node that uses them, and the last node of the arena
+/// is the root of the expression.
+///
+/// Variants:
+/// - `Const(c)`: the constant `c`
+/// - `Var(i)`: the variable `x_i`
+/// - `Add(a, b)`, `Sub(a, b)`, `Mul(a, b)`, `Div(a, b)`: arithmetic on two nodes
+/// - `Neg(a)`: negation of a node
+/// - `Powi(a, n)`: a node raised to the integer power `n`
+/// - `Powf(a, b)`: a node raised to the real power given by another node
+/// - `Apply(f, a)`: a `VarFunction` applied to a node
+/// - `Atan2(a, b)`: four-quadrant arctangent `atan2(a, b)` of two nodes
+/// - `Hypot(a, b)`: Euclidean norm `sqrt(a² + b²)` of two nodes
+#[derive(Clone, Copy)]
+pub enum Node {
+    Const(f64),                // c
+    Var(usize),                // x_i
+    Add(usize, usize),         // a + b
+    Sub(usize, usize),         // a - b
+    Mul(usize, usize),         // a * b
+    Div(usize, usize),         // a / b
+    Neg(usize),                // -a
+    Powi(usize, i32),          // a^n
+    Powf(usize, usize),        // a^b
+    Apply(VarFunction, usize), // f(a)
+    Atan2(usize, usize),       // atan2(a, b)
+    Hypot(usize, usize),       // sqrt(a² + b²)
+}
+
+/// Represents an arbitrary expression of NUM_VARIABLES variables, stored as an arena of
+/// NUM_NODES nodes.
+///
+/// Unlike `Polynomial`, which is a sum of separable products, an `Expr` can nest
+/// sums, products, quotients, powers and functions freely. Every node is evaluated once,
+/// however many nodes share it.
+///
+/// # Example
+///
+/// ```
+/// use const_poly::expr::{Expr, Node, Node::*};
+/// use const_poly::VarFunction::Sin;
+///
+/// // f(x, y) = sin(x² + 1) / y
+/// const NODES: [Node; 7] =
+///     [Var(0), Powi(0, 2), Const(1.0), Add(1, 2), Apply(Sin, 3), Var(1), Div(4, 5)];
+/// const EXPR: Expr<2, 7> = Expr::new(&NODES);
+///
+/// const VAL: f64 = EXPR.evaluate(&[1.0, 2.0]);
+/// assert!((VAL - 2.0f64.sin() / 2.0).abs() < 1e-9);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Expr<const NUM_VARIABLES: usize, const NUM_NODES: usize> {
+    nodes: &'static [Node; NUM_NODES],
+}
+
+impl<const NUM_VARIABLES: usize, const NUM_NODES: usize> Expr<NUM_VARIABLES, NUM_NODES> {
+    /// Creates a new `Expr` from a node arena whose last node is the root.
+    ///
+    /// Panics (at compile time, in a const context) if the arena is empty, if an operand
+    /// does not refer to an earlier node, or if a variable index is not below
+    /// `NUM_VARIABLES`.
+    ///
+    /// # Parameters
+    ///
+    /// - `nodes`: The node arena. Every operand index must be smaller than the index of
+    ///   the node using it.
+    ///
+    /// # Returns
+    ///
+    /// A new `Expr` instance.
+    pub const fn new(nodes: &'static [Node; NUM_NODES]) -> Self {
+        assert!(NUM_NODES > 0, "an Expr needs at least one node");
+
+        let mut k = 0;
+        while k < NUM_NODES {
+            let ordered = match nodes[k] {
+                Node::Const(_) => true,
+                Node::Var(i) => {
+                    assert!(i < NUM_VARIABLES, "Var index out of range");
+                    true
+                }
+                Node::Neg(a) | Node::Powi(a, _) | Node::Apply(_, a) => a < k,
+                Node::Add(a, b)
+                | Node::Sub(a, b)
+                | Node::Mul(a, b)
+                | Node::Div(a, b)
+                | Node::Powf(a, b)
+                | Node::Atan2(a, b)
+                | Node::Hypot(a, b) => a < k && b < k,
+            };
+            assert!(ordered, "operands must refer to earlier nodes");
+            k += 1;
+        }
+
+        Self { nodes }
+    }
+
+    /// Returns the node arena of the expression.
+    pub const fn nodes(&self) -> &'static [Node] {
+        self.nodes
+    }
+
+    /// Evaluates the expression at the given variable values.
+    ///
+    /// The nodes are evaluated in arena order, each into its own slot of a value buffer, so
+    /// the operands of every node are ready by the time it is reached.
+    ///
+    /// # Parameters
+    ///
+    /// - `vars`: An array of `NUM_VARIABLES` floating-point values representing the variables.
+    ///
+    /// # Returns
+    ///
+    /// The floating-point result of evaluating the expression.
+    pub const fn evaluate(&self, vars: &[f64; NUM_VARIABLES]) -> f64 {
+        let mut values = [0.0; NUM_NODES];
+        let mut k = 0;
+
+        while k < NUM_NODES {
+            values[k] = match self.nodes[k] {
+                Node::Const(c) => c,
+                Node::Var(i) => vars[i],
+                Node::Add(a, b) => values[a] + values[b],
+                Node::Sub(a, b) => values[a] - values[b],
+                Node::Mul(a, b) => values[a] * values[b],
+                Node::Div(a, b) => values[a] / values[b],
+                Node::Neg(a) => -values[a],
+                Node::Powi(a, n) => static_powi(values[a], n),
+                Node::Powf(a, b) => powf_approx(values[a], values[b]),
+                Node::Apply(function, a) => function.evaluate(values[a]),
+                Node::Atan2(a, b) => atan2_approx(values[a], values[b]),
+                Node::Hypot(a, b) => hypot_approx(values[a], values[b]),
+            };
+            k += 1;
+        }
+
+        values[NUM_NODES - 1]
+    }
+}
@@ -0,0 +1,111 @@
+use crate::function_approximations::static_powi;
+
+/// Enum representing a single step of a multivariate Horner scheme.
+///
+/// Nodes live in a flat arena (`&'static [HornerNode]`) and refer to earlier nodes by
+/// index; the last node of the arena is the root.
+///
+/// Variants:
+/// - `Const(c)`: the constant `c`
+/// - `MulAdd(a, i, n, b)`: `a · x_i^n + b` for the nodes `a` and `b`
+/// - `MulPow(a, i, n)`: `a · x_i^n` for the node `a`
+#[derive(Clone, Copy)]
+pub enum HornerNode {
+    Const(f64),                       // c
+    MulAdd(usize, usize, i32, usize), // a · x_i^n + b
+    MulPow(usize, usize, i32),        // a · x_i^n
+}
+
+/// Represents a polynomial of NUM_VARIABLES variables in nested Horner form, stored as an
+/// arena of NUM_NODES nodes.
+///
+/// The polynomial is factored on one variable at a time, so each variable is raised only to
+/// the gaps between consecutive exponents. This takes far fewer multiplications than
+/// evaluating every term's powers separately, and usually accumulates less rounding error.
+/// Build it from a `Pow`-only `Polynomial` with `poly_to_horner!`.
+///
+/// # Example
+///
+/// ```
+/// use const_poly::VarFunction::*;
+/// use const_poly::{const_poly, poly_to_horner, HornerPoly, Polynomial};
+///
+/// // f(x, y) = 2x³y² - x³ + 4y
+/// const F: Polynomial<2> = const_poly!({
+///     [2.0, Pow(3), Pow(2)],
+///     [-1.0, Pow(3), Pow(0)],
+///     [4.0, Pow(0), Identity]
+/// });
+/// const H: HornerPoly<2, { F.horner_len() }> = poly_to_horner!(F);
+///
+/// assert_eq!(H.evaluate(&[2.0, 3.0]), 148.0);
+/// ```
+#[derive(Clone, Copy)]
+pub struct HornerPoly<const NUM_VARIABLES: usize, const NUM_NODES: usize> {
+    nodes: &'static [HornerNode; NUM_NODES],
+}
+
+impl<const NUM_VARIABLES: usize, const NUM_NODES: usize> HornerPoly<NUM_VARIABLES, NUM_NODES> {
+    /// Creates a new `HornerPoly` from a node arena whose last node is the root, as written
+    /// by `Polynomial::to_horner_nodes`.
+    ///
+    /// Panics (at compile time, in a const context) if the arena is empty, if an operand
+    /// does not refer to an earlier node, or if a variable index is not below
+    /// `NUM_VARIABLES`.
+    pub const fn new(nodes: &'static [HornerNode; NUM_NODES]) -> Self {
+        assert!(NUM_NODES > 0, "a HornerPoly needs at least one node");
+
+        let mut k = 0;
+        while k < NUM_NODES {
+            let ordered = match nodes[k] {
+                HornerNode::Const(_) => true,
+                HornerNode::MulAdd(a, var, _, b) => {
+                    assert!(var < NUM_VARIABLES, "variable index out of range");
+                    a < k && b < k
+                }
+                HornerNode::MulPow(a, var, _) => {
+                    assert!(var < NUM_VARIABLES, "variable index out of range");
+                    a < k
+                }
+            };
+            assert!(ordered, "operands must refer to earlier nodes");
+            k += 1;
+        }
+
+        Self { nodes }
+    }
+
+    /// Returns the node arena of the Horner scheme.
+    pub const fn nodes(&self) -> &'static [HornerNode] {
+        self.nodes
+    }
+
+    /// Evaluates the polynomial at the given variable values.
+    ///
+    /// The nodes are evaluated in arena order, each into its own slot of a value buffer.
+    ///
+    /// # Parameters
+    ///
+    /// - `vars`: An array of `NUM_VARIABLES` floating-point values representing the variables.
+    ///
+    /// # Returns
+    ///
+    /// The floating-point result of evaluating the polynomial.
+    pub const fn evaluate(&self, vars: &[f64; NUM_VARIABLES]) -> f64 {
+        let mut values = [0.0; NUM_NODES];
+        let mut k = 0;
+
+        while k < NUM_NODES {
+            values[k] = match self.nodes[k] {
+                HornerNode::Const(c) => c,
+                HornerNode::MulAdd(a, var, exp, b) => {
+                    values[a] * static_powi(vars[var], exp) + values[b]
+                }
+                HornerNode::MulPow(a, var, exp) => values[a] * static_powi(vars[var], exp),
+            };
+            k += 1;
+        }
+
+        values[NUM_NODES - 1]
+    }
+}
@@ -1,7 +1,14 @@
 #![no_std]
 
+pub mod cached;
+pub mod correctly_rounded;
+pub mod dense;
+pub mod double_double;
+pub mod expr;
 pub mod function_approximations;
+pub mod horner;
 pub mod polynomial;
+pub mod sparse;
 pub mod term;
 
 #[macro_use]
@@ -11,7 +18,14 @@ pub mod macros;
 extern crate static_assertions; //for const_assert
 
 // Re-export key types
+pub use crate::cached::CachedPolynomial;
+pub use crate::dense::DensePoly;
+pub use crate::double_double::DoubleDouble;
+pub use crate::expr::Expr;
+pub use crate::horner::HornerPoly;
 pub use crate::polynomial::Polynomial;
+pub use crate::sparse::{SparsePolynomial, SparseTerm};
 pub use crate::term::Term;
 
+pub use crate::term::CrossFactor;
 pub use crate::term::VarFunction;
@@ -22,10 +22,13 @@ macro_rules! terms {
 /// Defines a compile-time polynomial with inferred variable count.
 /// Supports both single-term and multi-term forms.
 ///
+/// Cross-variable factors (`CrossFactor`s) can follow the per-variable functions of a term
+/// after a semicolon.
+///
 /// ## Examples
 ///
 /// ```
-/// use const_poly::{Polynomial, const_poly, VarFunction::*};
+/// use const_poly::{Polynomial, const_poly, CrossFactor::*, VarFunction::*};
 ///
 /// const POLY1: Polynomial<2> = const_poly!([1.0, Sin, Cos]);
 ///
@@ -33,28 +36,669 @@ macro_rules! terms {
 ///     [1.0, Identity, Identity],
 ///     [2.0, Sin, Pow(2)]
 /// });
+///
+/// // f(x, y) = atan2(y, x) + x * sin(x + y)
+/// const POLY3: Polynomial<2> = const_poly!({
+///     [1.0, Pow(0), Pow(0); Atan2(1, 0)],
+///     [1.0, Identity, Pow(0); Linear(Sin, [1.0, 1.0], 0.0)]
+/// });
 /// ```
 #[macro_export]
 macro_rules! const_poly {
-    // Single-term form: [ coeff, func1, func2, ... ]
-    ( [ $coeff:expr $(, $func:expr )* $(,)? ] ) => {{
+    // Single-term form: [ coeff, func1, func2, ... ; cross1, cross2, ... ]
+    ( [ $coeff:expr $(, $func:expr )* $(; $( $cross:expr ),+ )? $(,)? ] ) => {{
         const __N: usize = $crate::count_exprs!( $( $func ),* );
         const __TERMS: [ $crate::term::Term<__N>; 1 ] = [
-            $crate::term::Term::new($coeff, [ $( $func ),* ])
+            $crate::term::Term::with_cross($coeff, [ $( $func ),* ], &[ $( $( $cross ),+ )? ])
         ];
         $crate::polynomial::Polynomial::<__N>::new(&__TERMS)
     }};
 
-    // Multi-term form: { [coeff, func1, func2, ...], [coeff2, func1, ...], ... }
+    // Multi-term form: { [coeff, func1, func2, ...; cross...], [coeff2, func1, ...], ... }
     ({
-        [ $coeff_first:expr $(, $func_first:expr )* ]
-        $(, [ $coeff_rest:expr $(, $func_rest:expr )* ] )* $(,)?
+        [ $coeff_first:expr $(, $func_first:expr )* $(; $( $cross_first:expr ),+ )? ]
+        $(, [ $coeff_rest:expr $(, $func_rest:expr )* $(; $( $cross_rest:expr ),+ )? ] )* $(,)?
     }) => {{
         const __N: usize = $crate::count_exprs!( $( $func_first ),* );
         const __TERMS: &[ $crate::term::Term<__N> ] = &[
-            $crate::term::Term::new($coeff_first, [ $( $func_first ),* ]),
-            $( $crate::term::Term::new($coeff_rest, [ $( $func_rest ),* ]) ),*
+            $crate::term::Term::with_cross(
+                $coeff_first,
+                [ $( $func_first ),* ],
+                &[ $( $( $cross_first ),+ )? ]
+            ),
+            $(
+                $crate::term::Term::with_cross(
+                    $coeff_rest,
+                    [ $( $func_rest ),* ],
+                    &[ $( $( $cross_rest ),+ )? ]
+                )
+            ),*
         ];
         $crate::polynomial::Polynomial::<__N>::new(__TERMS)
     }};
 }
+
+/// Converts a `Polynomial` constant into an equivalent `Expr`, allocating its node arena.
+///
+/// ## Example
+///
+/// ```
+/// use const_poly::{Expr, Polynomial, const_poly, poly_to_expr, VarFunction::*};
+///
+/// const POLY: Polynomial<2> = const_poly!([3.0, Sin, Exp]);
+/// const EXPR: Expr<2, { POLY.expr_len() }> = poly_to_expr!(POLY);
+///
+/// assert!(EXPR.evaluate(&[0.3, 0.7]) == POLY.evaluate(&[0.3, 0.7]));
+/// ```
+#[macro_export]
+macro_rules! poly_to_expr {
+    ( $poly:path ) => {{
+        const __NODES: [$crate::expr::Node; $poly.expr_len()] = $poly.to_expr_nodes();
+        $crate::expr::Expr::new(&__NODES)
+    }};
+}
+
+/// Substitutes each variable of a `Polynomial` constant with the matching polynomial of an
+/// array constant, see `Polynomial::compose`.
+///
+/// ## Example
+///
+/// ```
+/// use const_poly::{Polynomial, const_poly, compose_poly, VarFunction::*};
+///
+/// // f(x) = x² + 1, with x = 2y - 1
+/// const F: Polynomial<1> = const_poly!({[1.0, Pow(2)], [1.0, Pow(0)]});
+/// const SUBS: [Polynomial<1>; 1] = [const_poly!({[2.0, Identity], [-1.0, Pow(0)]})];
+/// const G: Polynomial<1> = compose_poly!(F, SUBS);
+///
+/// assert!((G.evaluate_scalar(3.0) - 26.0).abs() < 1e-12);
+/// ```
+#[macro_export]
+macro_rules! compose_poly {
+    ( $poly:path, $subs:path ) => {{
+        const __TERMS: [$crate::term::Term<{ $subs[0].num_variables() }>;
+            $poly.compose_len(&$subs)] = $poly.compose(&$subs);
+        $crate::polynomial::Polynomial::new(&__TERMS)
+    }};
+}
+
+/// Substitutes each variable of a `Polynomial` constant with the matching polynomial of an
+/// array constant, producing an `Expr`, see `Polynomial::compose_expr_nodes`.
+///
+/// ## Example
+///
+/// ```
+/// use const_poly::{Expr, Polynomial, const_poly, compose_expr, VarFunction::*};
+///
+/// // f(x) = exp(x), with x = -(y²)
+/// const F: Polynomial<1> = const_poly!([1.0, Exp]);
+/// const SUBS: [Polynomial<1>; 1] = [const_poly!([-1.0, Pow(2)])];
+/// const G: Expr<1, { F.compose_expr_len(&SUBS) }> = compose_expr!(F, SUBS);
+///
+/// assert!((G.evaluate(&[2.0]) - (-4.0f64).exp()).abs() < 1e-12);
+/// ```
+#[macro_export]
+macro_rules! compose_expr {
+    ( $poly:path, $subs:path ) => {{
+        const __NODES: [$crate::expr::Node; $poly.compose_expr_len(&$subs)] =
+            $poly.compose_expr_nodes(&$subs);
+        $crate::expr::Expr::new(&__NODES)
+    }};
+}
+
+/// Binds some variables of a `Polynomial` constant to fixed values, see `Polynomial::bind`.
+///
+/// ## Example
+///
+/// ```
+/// use const_poly::{Polynomial, const_poly, bind_poly, VarFunction::*};
+///
+/// // f(x, y, z) = x · exp(y) · z², evaluated along the slice y = 1
+/// const F: Polynomial<3> = const_poly!([1.0, Identity, Exp, Pow(2)]);
+/// const G: Polynomial<2> = bind_poly!(F, [None, Some(1.0), None]);
+///
+/// assert!((G.evaluate(&[2.0, 3.0]) - F.evaluate(&[2.0, 1.0, 3.0])).abs() < 1e-12);
+/// ```
+#[macro_export]
+macro_rules! bind_poly {
+    ( $poly:path, $values:expr ) => {{
+        const __VALUES: [Option<f64>; $poly.num_variables()] = $values;
+        const __CROSS: [$crate::term::CrossFactor<
+            { $crate::polynomial::unbound_count(&__VALUES) },
+        >; $poly.bind_cross_len(&__VALUES)] = $poly.bind_cross(&__VALUES);
+        const __TERMS: [$crate::term::Term<{ $crate::polynomial::unbound_count(&__VALUES) }>;
+            $poly.num_terms()] = $poly.bind(&__VALUES, &__CROSS);
+        $crate::polynomial::Polynomial::new(&__TERMS)
+    }};
+}
+
+/// Embeds a `Polynomial` constant into `M` variables, moving variable `i` to index
+/// `mapping[i]`, see `Polynomial::embed`.
+///
+/// ## Example
+///
+/// ```
+/// use const_poly::{Polynomial, const_poly, embed_poly, VarFunction::*};
+///
+/// // f(x, y) = x·cos(y), as a function of (t, y, x)
+/// const F: Polynomial<2> = const_poly!([1.0, Identity, Cos]);
+/// const G: Polynomial<3> = embed_poly!(F, 3, [2, 1]);
+///
+/// assert!(G.evaluate(&[9.0, 0.5, 2.0]) == F.evaluate(&[2.0, 0.5]));
+/// ```
+#[macro_export]
+macro_rules! embed_poly {
+    ( $poly:path, $m:expr, $mapping:expr ) => {{
+        const __MAPPING: [usize; $poly.num_variables()] = $mapping;
+        const __CROSS: [$crate::term::CrossFactor<{ $m }>; $poly.cross_len()] =
+            $poly.embed_cross(&__MAPPING);
+        const __TERMS: [$crate::term::Term<{ $m }>; $poly.num_terms()] =
+            $poly.embed(&__MAPPING, &__CROSS);
+        $crate::polynomial::Polynomial::new(&__TERMS)
+    }};
+}
+
+/// Reorders the variables of a `Polynomial` constant, moving variable `i` to index
+/// `order[i]`, see `Polynomial::permute`.
+///
+/// ## Example
+///
+/// ```
+/// use const_poly::{Polynomial, const_poly, permute_poly, VarFunction::*};
+///
+/// // f(x, y) = x·cos(y), as g(y, x)
+/// const F: Polynomial<2> = const_poly!([1.0, Identity, Cos]);
+/// const G: Polynomial<2> = permute_poly!(F, [1, 0]);
+///
+/// assert!(G.evaluate(&[0.5, 2.0]) == F.evaluate(&[2.0, 0.5]));
+/// ```
+#[macro_export]
+macro_rules! permute_poly {
+    ( $poly:path, $order:expr ) => {{
+        const __ORDER: [usize; $poly.num_variables()] = $order;
+        const __CROSS: [$crate::term::CrossFactor<{ $poly.num_variables() }>; $poly.cross_len()] =
+            $poly.embed_cross(&__ORDER);
+        const __TERMS: [$crate::term::Term<{ $poly.num_variables() }>; $poly.num_terms()] =
+            $poly.permute(&__ORDER, &__CROSS);
+        $crate::polynomial::Polynomial::new(&__TERMS)
+    }};
+}
+
+/// Builds a `CachedPolynomial` from a `Polynomial` constant, computing its factor table
+/// and each term's slots in it at compile time.
+///
+/// ## Example
+///
+/// ```
+/// use const_poly::{CachedPolynomial, Polynomial, cache_poly, const_poly, VarFunction::*};
+///
+/// const F: Polynomial<2> = const_poly!({[1.0, Cos, Identity], [3.0, Cos, Pow(2)]});
+/// const C: CachedPolynomial<2, { F.factor_count() }> = cache_poly!(F);
+///
+/// assert_eq!(C.evaluate(&[0.3, 2.0]), F.evaluate(&[0.3, 2.0]));
+/// ```
+#[macro_export]
+macro_rules! cache_poly {
+    ( $poly:path ) => {{
+        const __SLOTS: [[usize; $poly.num_variables()]; $poly.num_terms()] =
+            $poly.factor_slots();
+        $crate::cached::CachedPolynomial::<{ $poly.num_variables() }, { $poly.factor_count() }>::new(
+            &$poly, &__SLOTS,
+        )
+    }};
+}
+
+/// Converts a `Pow`-only `Polynomial` constant into a `HornerPoly`, see
+/// `Polynomial::to_horner_nodes`.
+///
+/// ## Example
+///
+/// ```
+/// use const_poly::{HornerPoly, Polynomial, const_poly, poly_to_horner, VarFunction::*};
+///
+/// // f(x, y) = x²y + xy
+/// const F: Polynomial<2> = const_poly!({[1.0, Pow(2), Identity], [1.0, Identity, Identity]});
+/// const H: HornerPoly<2, { F.horner_len() }> = poly_to_horner!(F);
+///
+/// assert_eq!(H.evaluate(&[3.0, 2.0]), 24.0);
+/// ```
+#[macro_export]
+macro_rules! poly_to_horner {
+    ( $poly:path ) => {{
+        const __NODES: [$crate::horner::HornerNode; $poly.horner_len()] = $poly.to_horner_nodes();
+        $crate::horner::HornerPoly::<{ $poly.num_variables() }, { $poly.horner_len() }>::new(
+            &__NODES,
+        )
+    }};
+}
+
+/// Converts a `Pow`-only `Polynomial<1>` constant into a `DensePoly` of its degree, see
+/// `Polynomial::to_dense_coeffs`.
+///
+/// ## Example
+///
+/// ```
+/// use const_poly::{DensePoly, Polynomial, const_poly, poly_to_dense, VarFunction::*};
+///
+/// // f(x) = 3x² + 1
+/// const F: Polynomial<1> = const_poly!({[3.0, Pow(2)], [1.0, Pow(0)]});
+/// const P: DensePoly<2> = poly_to_dense!(F);
+///
+/// assert_eq!(P.evaluate(2.0), 13.0);
+/// ```
+#[macro_export]
+macro_rules! poly_to_dense {
+    ( $poly:path ) => {{
+        const __COEFFS: [f64; $poly.degree() + 1] = $poly.to_dense_coeffs();
+        $crate::dense::DensePoly::<{ $poly.degree() }>::new(&__COEFFS)
+    }};
+}
+
+/// Converts a `DensePoly` constant into a `Polynomial<1>` with one `Pow(k)` term per
+/// nonzero coefficient, see `DensePoly::to_terms`.
+///
+/// ## Example
+///
+/// ```
+/// use const_poly::{DensePoly, Polynomial, dense_to_poly};
+///
+/// const P: DensePoly<2> = DensePoly::new(&[1.0, 0.0, 3.0]);
+/// const F: Polynomial<1> = dense_to_poly!(P);
+///
+/// assert_eq!(F.num_terms(), 2);
+/// assert_eq!(F.evaluate_scalar(2.0), 13.0);
+/// ```
+#[macro_export]
+macro_rules! dense_to_poly {
+    ( $dense:path ) => {{
+        const __TERMS: [$crate::term::Term<1>; $dense.num_terms()] = $dense.to_terms();
+        $crate::polynomial::Polynomial::new(&__TERMS)
+    }};
+}
+
+/// Defines a compile-time sparse polynomial; the variable count comes from the declared
+/// `SparsePolynomial<N>` type. Each factor names its variable as `x<index>`, and variables
+/// not named in a term are left out of it.
+/// Supports both single-term and multi-term forms.
+///
+/// ## Examples
+///
+/// ```
+/// use const_poly::{SparsePolynomial, sparse_poly, VarFunction::*};
+///
+/// const POLY1: SparsePolynomial<50> = sparse_poly!([2.0, x3: Sin, x17: Pow(2)]);
+///
+/// const POLY2: SparsePolynomial<50> = sparse_poly!({
+///     [1.0, x0: Identity, x49: Exp],
+///     [-3.0, x12: Cos]
+/// });
+/// ```
+#[macro_export]
+macro_rules! sparse_poly {
+    // Single-term form: [ coeff, x3: func, x17: func, ... ]
+    ( [ $coeff:expr $(, $var:ident : $func:expr )* $(,)? ] ) => {{
+        const __TERMS: &[ $crate::sparse::SparseTerm ] = &[
+            $crate::sparse::SparseTerm::new($coeff, &[
+                $( (const { $crate::sparse::var_index(stringify!($var)) }, $func) ),*
+            ])
+        ];
+        $crate::sparse::SparsePolynomial::new(__TERMS)
+    }};
+
+    // Multi-term form: { [coeff, x3: func, ...], [coeff2, x0: func, ...], ... }
+    ({
+        $( [ $coeff:expr $(, $var:ident : $func:expr )* $(,)? ] ),* $(,)?
+    }) => {{
+        const __TERMS: &[ $crate::sparse::SparseTerm ] = &[
+            $(
+                $crate::sparse::SparseTerm::new($coeff, &[
+                    $( (const { $crate::sparse::var_index(stringify!($var)) }, $func) ),*
+                ])
+            ),*
+        ];
+        $crate::sparse::SparsePolynomial::new(__TERMS)
+    }};
+}
+
+/// Expands a single `VarFunction` applied to `$x` into a direct function call, see
+/// `const_poly_fn!`. Literal powers up to 8 are written out as the same chain of
+/// multiplications `static_powi` performs.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __poly_fn_factor {
+    (Identity, $x:expr) => {
+        $x
+    };
+    (Pow(0), $x:expr) => {
+        1.0
+    };
+    (Pow(1), $x:expr) => {
+        $x
+    };
+    (Pow(2), $x:expr) => {{
+        let x = $x;
+        x * x
+    }};
+    (Pow(3), $x:expr) => {{
+        let x = $x;
+        x * (x * x)
+    }};
+    (Pow(4), $x:expr) => {{
+        let x = $x;
+        let x2 = x * x;
+        x2 * x2
+    }};
+    (Pow(5), $x:expr) => {{
+        let x = $x;
+        let x2 = x * x;
+        x * (x2 * x2)
+    }};
+    (Pow(6), $x:expr) => {{
+        let x = $x;
+        let x2 = x * x;
+        x2 * (x2 * x2)
+    }};
+    (Pow(7), $x:expr) => {{
+        let x = $x;
+        let x2 = x * x;
+        x * x2 * (x2 * x2)
+    }};
+    (Pow(8), $x:expr) => {{
+        let x = $x;
+        let x2 = x * x;
+        let x4 = x2 * x2;
+        x4 * x4
+    }};
+    (Pow(- $n:literal), $x:expr) => {
+        1.0 / $crate::__poly_fn_factor!(Pow($n), $x)
+    };
+    (Pow($n:expr), $x:expr) => {
+        $crate::function_approximations::static_powi($x, $n)
+    };
+    (Sin, $x:expr) => {
+        $crate::function_approximations::sin_approx($x)
+    };
+    (Cos, $x:expr) => {
+        $crate::function_approximations::cos_approx($x)
+    };
+    (Tan, $x:expr) => {
+        $crate::function_approximations::tan_approx($x)
+    };
+    (Exp, $x:expr) => {
+        $crate::function_approximations::exp_approx($x)
+    };
+    (Ln, $x:expr) => {
+        $crate::function_approximations::ln_approx($x)
+    };
+    (Sqrt, $x:expr) => {
+        $crate::function_approximations::sqrt_approx($x)
+    };
+    (Arctan, $x:expr) => {
+        $crate::function_approximations::arctan_approx($x)
+    };
+    (Sinh, $x:expr) => {
+        $crate::function_approximations::sinh_approx($x)
+    };
+    (Cosh, $x:expr) => {
+        $crate::function_approximations::cosh_approx($x)
+    };
+    (Arcsin, $x:expr) => {
+        $crate::function_approximations::arcsin_approx($x)
+    };
+    (Arccos, $x:expr) => {
+        $crate::function_approximations::arccos_approx($x)
+    };
+    (Asinh, $x:expr) => {
+        $crate::function_approximations::asinh_approx($x)
+    };
+    (Acosh, $x:expr) => {
+        $crate::function_approximations::acosh_approx($x)
+    };
+    (Atanh, $x:expr) => {
+        $crate::function_approximations::atanh_approx($x)
+    };
+    (Cbrt, $x:expr) => {
+        $crate::function_approximations::cbrt_approx($x)
+    };
+    (Erf, $x:expr) => {
+        $crate::function_approximations::erf_approx($x)
+    };
+    (Erfc, $x:expr) => {
+        $crate::function_approximations::erfc_approx($x)
+    };
+    (NormPdf, $x:expr) => {
+        $crate::function_approximations::norm_pdf_approx($x)
+    };
+    (NormCdf, $x:expr) => {
+        $crate::function_approximations::norm_cdf_approx($x)
+    };
+    (Probit, $x:expr) => {
+        $crate::function_approximations::probit_approx($x)
+    };
+    (Gamma, $x:expr) => {
+        $crate::function_approximations::gamma_approx($x)
+    };
+    (LnGamma, $x:expr) => {
+        $crate::function_approximations::lgamma_approx($x)
+    };
+    (BesselJ0, $x:expr) => {
+        $crate::function_approximations::bessel_j0_approx($x)
+    };
+    (BesselJ1, $x:expr) => {
+        $crate::function_approximations::bessel_j1_approx($x)
+    };
+    (BesselY0, $x:expr) => {
+        $crate::function_approximations::bessel_y0_approx($x)
+    };
+    (BesselY1, $x:expr) => {
+        $crate::function_approximations::bessel_y1_approx($x)
+    };
+    (BesselI0, $x:expr) => {
+        $crate::function_approximations::bessel_i0_approx($x)
+    };
+    (BesselK0, $x:expr) => {
+        $crate::function_approximations::bessel_k0_approx($x)
+    };
+    (Sigmoid, $x:expr) => {
+        $crate::function_approximations::sigmoid_approx($x)
+    };
+    (Softplus, $x:expr) => {
+        $crate::function_approximations::softplus_approx($x)
+    };
+    (Relu, $x:expr) => {
+        $crate::function_approximations::relu($x)
+    };
+    (Gelu, $x:expr) => {
+        $crate::function_approximations::gelu_approx($x)
+    };
+    (Swish, $x:expr) => {
+        $crate::function_approximations::swish_approx($x)
+    };
+    (Abs, $x:expr) => {
+        $crate::function_approximations::absolute($x)
+    };
+    (Sign, $x:expr) => {
+        $crate::function_approximations::sign($x)
+    };
+    (Floor, $x:expr) => {
+        $crate::function_approximations::floor($x)
+    };
+    (Ceil, $x:expr) => {
+        $crate::function_approximations::ceil($x)
+    };
+    (Round, $x:expr) => {
+        $crate::function_approximations::round($x)
+    };
+    (Heaviside, $x:expr) => {
+        $crate::function_approximations::heaviside($x)
+    };
+    (Sec, $x:expr) => {
+        $crate::function_approximations::sec_approx($x)
+    };
+    (Csc, $x:expr) => {
+        $crate::function_approximations::csc_approx($x)
+    };
+    (Cot, $x:expr) => {
+        $crate::function_approximations::cot_approx($x)
+    };
+    (SinDeg, $x:expr) => {
+        $crate::function_approximations::sin_deg_approx($x)
+    };
+    (CosDeg, $x:expr) => {
+        $crate::function_approximations::cos_deg_approx($x)
+    };
+    (TanDeg, $x:expr) => {
+        $crate::function_approximations::tan_deg_approx($x)
+    };
+    (LambertW, $x:expr) => {
+        $crate::function_approximations::lambert_w0_approx($x)
+    };
+    (EllipticK, $x:expr) => {
+        $crate::function_approximations::elliptic_k_approx($x)
+    };
+    (EllipticE, $x:expr) => {
+        $crate::function_approximations::elliptic_e_approx($x)
+    };
+    (SinCr, $x:expr) => {
+        $crate::correctly_rounded::sin_cr($x)
+    };
+    (CosCr, $x:expr) => {
+        $crate::correctly_rounded::cos_cr($x)
+    };
+    (ExpCr, $x:expr) => {
+        $crate::correctly_rounded::exp_cr($x)
+    };
+    (LnCr, $x:expr) => {
+        $crate::correctly_rounded::ln_cr($x)
+    };
+    (SqrtCr, $x:expr) => {
+        $crate::correctly_rounded::sqrt_cr($x)
+    };
+    (PowFrac($p:expr, $q:expr), $x:expr) => {
+        $crate::function_approximations::pow_frac_approx($x, $p, $q)
+    };
+    (PowF($y:expr), $x:expr) => {
+        $crate::function_approximations::powf_approx($x, $y)
+    };
+    (LeakyRelu($alpha:expr), $x:expr) => {
+        $crate::function_approximations::leaky_relu($x, $alpha)
+    };
+    (Clamp($lo:expr, $hi:expr), $x:expr) => {
+        $crate::function_approximations::clamp($x, $lo, $hi)
+    };
+    (Scaled(& $f:ident $( ( $($param:tt)* ) )?, $scale:expr, $offset:expr), $x:expr) => {{
+        let scale: f64 = $scale;
+        let offset: f64 = $offset;
+        $crate::__poly_fn_factor!($f $( ( $($param)* ) )?, scale * $x + offset)
+    }};
+}
+
+/// Emits the statements of the terms of a `const_poly_fn!` body, last term first, see
+/// `__poly_fn_body!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __poly_fn_terms {
+    ($acc:ident, $args:tt, $t:expr;) => {};
+    (
+        $acc:ident, $args:tt, $t:expr;
+        [$coeff:expr $(, { $($func:tt)* })*] $(, $rest:tt)*
+    ) => {
+        $crate::__poly_fn_terms!($acc, $args, $t + 1; $($rest),*);
+        $acc[__POLY.num_variables()][const { __GROUPS[__POLY.num_variables() - 1][$t] }] += $coeff;
+        $crate::__poly_fn_levels!($acc, $t, 0; $args; [$({ $($func)* }),*]);
+    };
+}
+
+/// Emits the statements of one term of a `const_poly_fn!` body, last variable first: if the
+/// term is the first of its group on `x_i`, the group's sum is multiplied by the term's
+/// factor of `x_i` and added to the enclosing group.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __poly_fn_levels {
+    ($acc:ident, $t:expr, $i:expr; []; []) => {};
+    (
+        $acc:ident, $t:expr, $i:expr;
+        [$arg:ident $(, $args:ident)*];
+        [{ $($func:tt)* } $(, $funcs:tt)*]
+    ) => {
+        $crate::__poly_fn_levels!($acc, $t, $i + 1; [$($args),*]; [$($funcs),*]);
+        if const { __GROUPS[$i][$t] == $t } {
+            $acc[$i][const { if $i == 0 { 0 } else { __GROUPS[$i - 1][$t] } }] +=
+                $crate::__poly_fn_factor!($($func)*, $arg) * $acc[$i + 1][$t];
+        }
+    };
+    ($acc:ident, $t:expr, $i:expr; $args:tt; $funcs:tt) => {
+        compile_error!("each term of const_poly_fn! needs exactly one function per argument")
+    };
+}
+
+/// Evaluates a `const_poly_fn!` body, factoring out the functions shared by several terms.
+///
+/// `acc[i][g]` accumulates the sum of group `g` over the variables from `x_i` on. Every
+/// group test and array index is a constant, so the expansion is straight-line code.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __poly_fn_body {
+    ($args:tt; $( [ $coeff:expr $(, $func:ident $( ( $($param:tt)* ) )? )* $(,)? ] ),* $(,)?) => {{
+        use $crate::term::VarFunction::*;
+
+        const __POLY: $crate::polynomial::Polynomial<{ $crate::count_exprs! $args }> =
+            $crate::const_poly!({ $( [ $coeff $(, $func $( ( $($param)* ) )? )* ] ),* });
+        const __GROUPS: [[usize; __POLY.num_terms()]; __POLY.num_variables()] =
+            __POLY.factor_prefix_groups();
+
+        // One spare group keeps acc[0][0] in bounds for an empty body
+        let mut acc = [[0.0; __POLY.num_terms() + 1]; __POLY.num_variables() + 1];
+        $crate::__poly_fn_terms!(
+            acc, $args, 0;
+            $( [$coeff $(, { $func $( ( $($param)* ) )? })*] ),*
+        );
+        acc[0][0]
+    }};
+}
+
+/// Generates a `const fn` evaluating a polynomial, with one `f64` argument per variable.
+///
+/// The body uses the `const_poly!` term syntax, and every function is expanded into a
+/// direct call when the macro is expanded, so no `VarFunction` is matched at run time.
+/// Integer powers up to 8 become plain multiplications.
+///
+/// The terms are factored Horner-style on the functions they share: like terms are summed,
+/// and terms with the same functions of `x_0`, then of `x_0` and `x_1`, and so on, share one
+/// evaluation and one multiplication of them, e.g. `2·sin(x)·y + 3·sin(x)·y²` is computed as
+/// `sin(x)·(y·2 + y²·3)`. The grouping is found at compile time and resolved into
+/// straight-line code, with no loops or branches left at run time. The result may differ from
+/// `Polynomial::evaluate` in the last bits, since the operations are ordered differently.
+///
+/// Cross-variable factors are not supported.
+///
+/// ## Example
+///
+/// ```
+/// use const_poly::{Polynomial, const_poly, const_poly_fn, VarFunction::*};
+///
+/// const_poly_fn!(
+///     /// f(x, y) = 1.5·sin(x)·y² − 2·cos(x)·y³
+///     pub fn my_model(x, y) {
+///         [1.5, Sin, Pow(2)],
+///         [-2.0, Cos, Pow(3)]
+///     }
+/// );
+///
+/// const POLY: Polynomial<2> = const_poly!({[1.5, Sin, Pow(2)], [-2.0, Cos, Pow(3)]});
+/// const VAL: f64 = my_model(0.5, 1.5);
+///
+/// assert!((VAL - POLY.evaluate(&[0.5, 1.5])).abs() < 1e-15);
+/// ```
+#[macro_export]
+macro_rules! const_poly_fn {
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident ( $( $arg:ident ),+ $(,)? ) { $($body:tt)* }
+    ) => {
+        $(#[$attr])*
+        $vis const fn $name( $( $arg: f64 ),+ ) -> f64 {
+            $crate::__poly_fn_body!([$( $arg ),+]; $($body)*)
+        }
+    };
+}
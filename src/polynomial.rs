@@ -1,4 +1,8 @@
-use crate::term::Term;
+use crate::double_double::DoubleDouble;
+use crate::expr::Node;
+use crate::function_approximations::{static_powi, two_sum};
+use crate::horner::HornerNode;
+use crate::term::{CrossFactor, Term, VarFunction};
 
 /// Represents a polynomial with a fixed number of variables and terms.
 ///
@@ -23,7 +27,7 @@ use crate::term::Term;
 /// assert!((result - 3.0).abs() < 1e-6);
 /// ```
 pub struct Polynomial<const NUM_VARIABLES: usize> {
-    terms: &'static [Term<NUM_VARIABLES>],
+    pub(crate) terms: &'static [Term<NUM_VARIABLES>],
 }
 
 impl<const NUM_VARIABLES: usize> Polynomial<NUM_VARIABLES> {
@@ -72,6 +76,1308 @@ impl<const NUM_VARIABLES: usize> Polynomial<NUM_VARIABLES> {
         }
         sum
     }
+
+    /// Evaluates the polynomial in double-double arithmetic (about 32 significant digits).
+    ///
+    /// Sums, products, powers and the functions listed in `VarFunction::evaluate_dd` are
+    /// computed in double-double; other functions and cross-variable factors contribute
+    /// their f64 values. Round the result with `DoubleDouble::to_f64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::VarFunction::*;
+    /// use const_poly::{const_poly, DoubleDouble, Polynomial};
+    ///
+    /// // f(x) = x² - 2 at x = √2 rounded to f64
+    /// const POLY: Polynomial<1> = const_poly!({[1.0, Pow(2)], [-2.0, Pow(0)]});
+    /// const X: f64 = 1.4142135623730951;
+    ///
+    /// // In f64, x² rounds to 2.0000000000000004; in double-double it is exact
+    /// assert_eq!(POLY.evaluate(&[X]), 4.440892098500626e-16);
+    /// assert_eq!(POLY.evaluate_dd(&[X]).to_f64(), 2.7343234630647693e-16);
+    /// ```
+    pub const fn evaluate_dd(&self, vars: &[f64; NUM_VARIABLES]) -> DoubleDouble {
+        let mut sum = DoubleDouble::from_f64(0.0);
+        let mut i = 0;
+
+        while i < self.terms.len() {
+            sum = sum.add(self.terms[i].evaluate_dd(vars));
+            i += 1;
+        }
+        sum
+    }
+
+    /// Evaluates the polynomial like `evaluate`, but with compensated arithmetic.
+    ///
+    /// The rounding error of every coefficient and factor multiplication is tracked with
+    /// error-free TwoProduct transforms, and the terms and their errors are added with
+    /// Neumaier summation. The function values themselves are the same as in `evaluate`.
+    /// This mainly helps when large terms cancel, where plain summation loses digits.
+    /// Terms that overflow give ±∞ (or NaN for ∞ - ∞), as in `evaluate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::VarFunction::*;
+    /// use const_poly::{const_poly, Polynomial};
+    ///
+    /// // f(x) = 1e16·x + 1 - 1e16·x, where plain summation loses the 1
+    /// const POLY: Polynomial<1> = const_poly!({
+    ///     [1e16, Identity],
+    ///     [1.0, Pow(0)],
+    ///     [-1e16, Identity]
+    /// });
+    ///
+    /// assert_eq!(POLY.evaluate(&[1.0]), 0.0);
+    /// assert_eq!(POLY.evaluate_compensated(&[1.0]), 1.0);
+    /// ```
+    pub const fn evaluate_compensated(&self, vars: &[f64; NUM_VARIABLES]) -> f64 {
+        let mut sum = 0.0;
+        let mut compensation = 0.0;
+        let mut i = 0;
+
+        while i < self.terms.len() {
+            let (value, error) = self.terms[i].evaluate_compensated(vars);
+            (sum, compensation) = neumaier_add(sum, compensation, value);
+            (sum, compensation) = neumaier_add(sum, compensation, error);
+            i += 1;
+        }
+
+        // The compensation of an infinite or NaN sum is NaN and must not mask an overflow
+        if !sum.is_finite() {
+            return sum;
+        }
+        sum + compensation
+    }
+
+    /// Returns the number of variables of the polynomial.
+    pub const fn num_variables(&self) -> usize {
+        NUM_VARIABLES
+    }
+
+    /// Returns the number of nodes needed to represent the polynomial as an `Expr`.
+    ///
+    /// Use it as the arena length for `to_expr_nodes`.
+    pub const fn expr_len(&self) -> usize {
+        NUM_VARIABLES + self.expr_body_len()
+    }
+
+    /// Converts the polynomial into an expression node arena of length `LEN`.
+    ///
+    /// The conversion is lossless: the resulting `Expr` performs the same operations in the
+    /// same order as `evaluate`, so both give bit-for-bit identical results.
+    ///
+    /// Panics (at compile time, in a const context) if `LEN` differs from `expr_len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::VarFunction::*;
+    /// use const_poly::expr::{Expr, Node};
+    /// use const_poly::{const_poly, Polynomial};
+    ///
+    /// const POLY: Polynomial<2> = const_poly!({[1.0, Sin, Cos], [2.0, Pow(2), Identity]});
+    /// const NODES: [Node; POLY.expr_len()] = POLY.to_expr_nodes();
+    /// const EXPR: Expr<2, { POLY.expr_len() }> = Expr::new(&NODES);
+    ///
+    /// assert!(EXPR.evaluate(&[0.5, 1.5]) == POLY.evaluate(&[0.5, 1.5]));
+    /// ```
+    pub const fn to_expr_nodes<const LEN: usize>(&self) -> [Node; LEN] {
+        assert!(LEN == self.expr_len(), "arena length must equal expr_len()");
+
+        let mut nodes = [Node::Const(0.0); LEN];
+        let mut var_nodes = [0; NUM_VARIABLES];
+        let mut i = 0;
+
+        while i < NUM_VARIABLES {
+            nodes[i] = Node::Var(i);
+            var_nodes[i] = i;
+            i += 1;
+        }
+
+        self.write_expr_nodes(&mut nodes, NUM_VARIABLES, &var_nodes);
+        nodes
+    }
+
+    /// Returns the number of terms produced by `compose` with the given substitutions.
+    ///
+    /// Use it as the array length for `compose`.
+    pub const fn compose_len<const M: usize>(
+        &self,
+        subs: &[Polynomial<M>; NUM_VARIABLES],
+    ) -> usize {
+        let mut len: usize = 0;
+        let mut t = 0;
+
+        while t < self.terms.len() {
+            let total = len.checked_add(composed_term_count(&self.terms[t], subs));
+            assert!(
+                total.is_some(),
+                "compose: the total term count overflows usize"
+            );
+            len = total.unwrap();
+            t += 1;
+        }
+        len
+    }
+
+    /// Substitutes each variable `x_i` with the polynomial `subs[i]` of `M` variables,
+    /// returning the `LEN` terms of the resulting `Polynomial<M>`.
+    ///
+    /// Power factors (`Pow(n)` with `n ≥ 0`, `Identity`) are expanded by multiplying out the
+    /// substituted sums; like terms are not collected. Negative powers require a single-term
+    /// substitute. Any other function is kept as a `Scaled` factor, which requires its
+    /// substitute to be affine in a single variable (`a * y_j + b`), or is folded into the
+    /// coefficient if the substitute is constant.
+    ///
+    /// Panics (at compile time, in a const context) if `LEN` differs from `compose_len()`, if
+    /// the term count overflows `usize`, or if the result is not expressible as a
+    /// `Polynomial`, e.g. when two non-power factors land on the same variable or a
+    /// cross-variable factor is involved. Such compositions can be built as an `Expr` with
+    /// `compose_expr_nodes` instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::VarFunction::*;
+    /// use const_poly::{const_poly, Polynomial, Term};
+    ///
+    /// // f(x, y) = x² · sin(y), with x = u + 1 and y = 2v - 0.5
+    /// const F: Polynomial<2> = const_poly!([1.0, Pow(2), Sin]);
+    /// const SUBS: [Polynomial<2>; 2] = [
+    ///     const_poly!({[1.0, Identity, Pow(0)], [1.0, Pow(0), Pow(0)]}),
+    ///     const_poly!({[2.0, Pow(0), Identity], [-0.5, Pow(0), Pow(0)]}),
+    /// ];
+    /// const TERMS: [Term<2>; F.compose_len(&SUBS)] = F.compose(&SUBS);
+    /// const G: Polynomial<2> = Polynomial::new(&TERMS);
+    ///
+    /// let (u, v) = (0.3, 0.4);
+    /// assert!((G.evaluate(&[u, v]) - F.evaluate(&[u + 1.0, 2.0 * v - 0.5])).abs() < 1e-9);
+    /// ```
+    pub const fn compose<const M: usize, const LEN: usize>(
+        &self,
+        subs: &[Polynomial<M>; NUM_VARIABLES],
+    ) -> [Term<M>; LEN] {
+        assert!(
+            LEN == self.compose_len(subs),
+            "array length must equal compose_len()"
+        );
+
+        let mut out = [Term::new(0.0, [VarFunction::Pow(0); M]); LEN];
+        let terms: &'static [Term<NUM_VARIABLES>] = self.terms;
+        let mut next = 0;
+        let mut t = 0;
+
+        while t < terms.len() {
+            let term: &'static Term<NUM_VARIABLES> = &terms[t];
+            let count = composed_term_count(term, subs);
+            let mut k = 0;
+
+            while k < count {
+                out[next] = composed_term(term, subs, k);
+                next += 1;
+                k += 1;
+            }
+            t += 1;
+        }
+        out
+    }
+
+    /// Returns the number of nodes produced by `compose_expr_nodes` with the given
+    /// substitutions.
+    pub const fn compose_expr_len<const M: usize>(
+        &self,
+        subs: &[Polynomial<M>; NUM_VARIABLES],
+    ) -> usize {
+        let mut len = M + self.expr_body_len();
+        let mut i = 0;
+
+        while i < NUM_VARIABLES {
+            len += subs[i].expr_body_len();
+            i += 1;
+        }
+        len
+    }
+
+    /// Substitutes each variable `x_i` with the polynomial `subs[i]` of `M` variables,
+    /// returning the node arena of the resulting `Expr<M, LEN>`.
+    ///
+    /// Unlike `compose`, this works for any polynomials, since every function is applied to
+    /// its substituted sub-expression as is.
+    ///
+    /// Panics (at compile time, in a const context) if `LEN` differs from
+    /// `compose_expr_len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::VarFunction::*;
+    /// use const_poly::expr::{Expr, Node};
+    /// use const_poly::{const_poly, Polynomial};
+    ///
+    /// // f(x) = sin(x), with x = u² + v²
+    /// const F: Polynomial<1> = const_poly!([1.0, Sin]);
+    /// const SUBS: [Polynomial<2>; 1] = [const_poly!({[1.0, Pow(2), Pow(0)], [1.0, Pow(0), Pow(2)]})];
+    /// const NODES: [Node; F.compose_expr_len(&SUBS)] = F.compose_expr_nodes(&SUBS);
+    /// const G: Expr<2, { F.compose_expr_len(&SUBS) }> = Expr::new(&NODES);
+    ///
+    /// assert!((G.evaluate(&[0.3, 0.4]) - 0.25f64.sin()).abs() < 1e-9);
+    /// ```
+    pub const fn compose_expr_nodes<const M: usize, const LEN: usize>(
+        &self,
+        subs: &[Polynomial<M>; NUM_VARIABLES],
+    ) -> [Node; LEN] {
+        assert!(
+            LEN == self.compose_expr_len(subs),
+            "arena length must equal compose_expr_len()"
+        );
+
+        let mut nodes = [Node::Const(0.0); LEN];
+        let mut inner_vars = [0; M];
+        let mut j = 0;
+
+        while j < M {
+            nodes[j] = Node::Var(j);
+            inner_vars[j] = j;
+            j += 1;
+        }
+
+        let mut var_nodes = [0; NUM_VARIABLES];
+        let mut next = M;
+        let mut i = 0;
+
+        while i < NUM_VARIABLES {
+            next = subs[i].write_expr_nodes(&mut nodes, next, &inner_vars);
+            var_nodes[i] = next - 1;
+            i += 1;
+        }
+
+        self.write_expr_nodes(&mut nodes, next, &var_nodes);
+        nodes
+    }
+
+    /// Returns the number of nodes written by `to_horner_nodes`.
+    ///
+    /// Panics (at compile time, in a const context) under the same conditions as
+    /// `to_horner_nodes`.
+    pub const fn horner_len(&self) -> usize {
+        let mut prefix = [0; NUM_VARIABLES];
+        horner_nodes(self.terms, 0, &mut prefix, &mut [], 0, false).1
+    }
+
+    /// Rewrites a polynomial made only of `Pow(n)` (n ≥ 0) and `Identity` factors as a
+    /// nested Horner scheme, for use with `HornerPoly`.
+    ///
+    /// The terms are factored on `x_0` first, then each group on `x_1`, and so on, adding up
+    /// like terms, e.g. `x²y + 3xy + 2x + 1` becomes `((y)·x + (3y + 2))·x + 1`.
+    ///
+    /// Panics (at compile time, in a const context) if `LEN` differs from `horner_len()`, or
+    /// if a term holds any other function, a negative power or a cross-variable factor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::VarFunction::*;
+    /// use const_poly::horner::HornerNode;
+    /// use const_poly::{const_poly, HornerPoly, Polynomial};
+    ///
+    /// // f(x, y) = x²y + 3xy + 2x + 1
+    /// const F: Polynomial<2> = const_poly!({
+    ///     [1.0, Pow(2), Identity],
+    ///     [3.0, Identity, Identity],
+    ///     [2.0, Identity, Pow(0)],
+    ///     [1.0, Pow(0), Pow(0)]
+    /// });
+    /// const NODES: [HornerNode; F.horner_len()] = F.to_horner_nodes();
+    /// const H: HornerPoly<2, { F.horner_len() }> = HornerPoly::new(&NODES);
+    ///
+    /// assert_eq!(H.evaluate(&[2.0, 5.0]), 55.0);
+    /// ```
+    pub const fn to_horner_nodes<const LEN: usize>(&self) -> [HornerNode; LEN] {
+        assert!(
+            LEN == self.horner_len(),
+            "array length must equal horner_len()"
+        );
+
+        let mut nodes = [HornerNode::Const(0.0); LEN];
+        let mut prefix = [0; NUM_VARIABLES];
+        horner_nodes(self.terms, 0, &mut prefix, &mut nodes, 0, true);
+        nodes
+    }
+
+    /// Returns the number of distinct `(variable, VarFunction)` factors over all terms, i.e.
+    /// the number of function evaluations done by a `CachedPolynomial`. Used to size it.
+    #[doc(hidden)]
+    pub const fn factor_count(&self) -> usize {
+        let mut count = 0;
+        let mut t = 0;
+
+        while t < self.terms.len() {
+            let mut i = 0;
+            while i < NUM_VARIABLES {
+                if find_factor(self.terms, t, i) == t {
+                    count += 1;
+                }
+                i += 1;
+            }
+            t += 1;
+        }
+        count
+    }
+
+    /// Returns the distinct `(variable, VarFunction)` factors of the polynomial, in order of
+    /// first appearance (term by term, then variable by variable). Used by
+    /// `CachedPolynomial::new`.
+    ///
+    /// Panics (at compile time, in a const context) if `LEN` differs from `factor_count()`.
+    #[doc(hidden)]
+    pub const fn factor_table<const LEN: usize>(&self) -> [(usize, VarFunction); LEN] {
+        assert!(
+            LEN == self.factor_count(),
+            "array length must equal factor_count()"
+        );
+
+        let mut table = [(0, VarFunction::Identity); LEN];
+        let mut next = 0;
+        let mut t = 0;
+
+        while t < self.terms.len() {
+            let mut i = 0;
+            while i < NUM_VARIABLES {
+                if find_factor(self.terms, t, i) == t {
+                    table[next] = (i, self.terms[t].functions[i]);
+                    next += 1;
+                }
+                i += 1;
+            }
+            t += 1;
+        }
+        table
+    }
+
+    /// Returns, for each term and variable, the index of its factor in `factor_table()`.
+    /// Used by `cache_poly!`.
+    ///
+    /// Panics (at compile time, in a const context) if `LEN` differs from `num_terms()`.
+    #[doc(hidden)]
+    pub const fn factor_slots<const LEN: usize>(&self) -> [[usize; NUM_VARIABLES]; LEN] {
+        assert!(
+            LEN == self.terms.len(),
+            "array length must equal num_terms()"
+        );
+
+        // Table index of the factor first appearing at each (term, variable) position
+        let mut slots = [[0; NUM_VARIABLES]; LEN];
+        let mut next = 0;
+        let mut t = 0;
+
+        while t < LEN {
+            let mut i = 0;
+            while i < NUM_VARIABLES {
+                let first = find_factor(self.terms, t, i);
+                if first == t {
+                    slots[t][i] = next;
+                    next += 1;
+                } else {
+                    slots[t][i] = slots[first][i];
+                }
+                i += 1;
+            }
+            t += 1;
+        }
+        slots
+    }
+
+    /// Returns, for each variable `i` and term `t`, the first term whose factors of the
+    /// variables `0..=i` are all the same as those of term `t`.
+    ///
+    /// The terms sharing a row entry form the groups of a Horner-style factoring on the
+    /// functions of `x_0`, then `x_1`, and so on. Used by `const_poly_fn!`.
+    ///
+    /// Panics (at compile time, in a const context) if `LEN` differs from `num_terms()`.
+    #[doc(hidden)]
+    pub const fn factor_prefix_groups<const LEN: usize>(&self) -> [[usize; LEN]; NUM_VARIABLES] {
+        assert!(
+            LEN == self.terms.len(),
+            "array length must equal num_terms()"
+        );
+
+        let mut groups = [[0; LEN]; NUM_VARIABLES];
+        let mut i = 0;
+
+        while i < NUM_VARIABLES {
+            let mut t = 0;
+            while t < LEN {
+                // The first term of the same group on x_0..x_(i-1) with the same factor of x_i
+                let mut s = if i == 0 { 0 } else { groups[i - 1][t] };
+                while s < t {
+                    let same_group = i == 0 || groups[i - 1][s] == groups[i - 1][t];
+                    if same_group && self.terms[s].functions[i].same_as(&self.terms[t].functions[i])
+                    {
+                        break;
+                    }
+                    s += 1;
+                }
+                groups[i][t] = s;
+                t += 1;
+            }
+            i += 1;
+        }
+        groups
+    }
+
+    /// Returns the number of terms of the polynomial.
+    pub const fn num_terms(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns the number of cross-variable factors that `bind` keeps as cross-variable
+    /// factors of the result, over all terms.
+    ///
+    /// Use it as the array length for `bind_cross`.
+    pub const fn bind_cross_len(&self, values: &[Option<f64>; NUM_VARIABLES]) -> usize {
+        let mut len = 0;
+        let mut t = 0;
+
+        while t < self.terms.len() {
+            let mut k = 0;
+            while k < self.terms[t].cross.len() {
+                if is_kept_cross(&self.terms[t], k, values) {
+                    len += 1;
+                }
+                k += 1;
+            }
+            t += 1;
+        }
+        len
+    }
+
+    /// Returns the cross-variable factors that `bind` keeps, in order, over the variables
+    /// left free by `values` and with the bound variables folded in.
+    ///
+    /// Panics (at compile time, in a const context) if `M` differs from the number of `None`
+    /// entries, if `LEN` differs from `bind_cross_len()`, or if an `Atan2` or `Hypot` factor
+    /// has one variable bound and the other free.
+    pub const fn bind_cross<const M: usize, const LEN: usize>(
+        &self,
+        values: &[Option<f64>; NUM_VARIABLES],
+    ) -> [CrossFactor<M>; LEN] {
+        assert!(
+            M == unbound_count(values),
+            "M must equal the number of unbound variables"
+        );
+        assert!(
+            LEN == self.bind_cross_len(values),
+            "array length must equal bind_cross_len()"
+        );
+
+        let positions = unbound_positions(values);
+        let mut out = [CrossFactor::Atan2(0, 0); LEN];
+        let mut next = 0;
+        let mut t = 0;
+
+        while t < self.terms.len() {
+            let term = &self.terms[t];
+            let mut k = 0;
+
+            while k < term.cross.len() {
+                if is_kept_cross(term, k, values) {
+                    out[next] = bind_cross_factor(&term.cross[k], values, &positions);
+                    next += 1;
+                }
+                k += 1;
+            }
+            t += 1;
+        }
+        out
+    }
+
+    /// Binds the variables with `Some(value)` in `values` to those constants, returning the
+    /// terms of a `Polynomial<M>` over the remaining variables, in their original order.
+    ///
+    /// Factors of bound variables are evaluated once and folded into each term's coefficient.
+    /// A cross-variable factor is folded as well if all of its variables are bound. If a
+    /// `Linear` or `Product` factor keeps a single free variable whose own factor is
+    /// `Pow(0)`, it becomes a `Scaled` factor of that variable. Any other cross-variable
+    /// factor stays one, taken from `cross`, which must hold the result of `bind_cross` with
+    /// the same values.
+    ///
+    /// Panics (at compile time, in a const context) if `M` differs from the number of `None`
+    /// entries, if `LEN` differs from `num_terms()`, if `cross` has the wrong length, or if
+    /// an `Atan2` or `Hypot` factor has one variable bound and the other free.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::VarFunction::*;
+    /// use const_poly::{const_poly, CrossFactor, Polynomial, Term};
+    ///
+    /// // f(x, t) = sin(x) · exp(-t), with the temperature t fixed to 0.5
+    /// const F: Polynomial<2> = const_poly!([2.0, Sin, Scaled(&Exp, -1.0, 0.0)]);
+    /// const VALUES: [Option<f64>; 2] = [None, Some(0.5)];
+    /// const CROSS: [CrossFactor<1>; F.bind_cross_len(&VALUES)] = F.bind_cross(&VALUES);
+    /// const TERMS: [Term<1>; F.num_terms()] = F.bind(&VALUES, &CROSS);
+    /// const G: Polynomial<1> = Polynomial::new(&TERMS);
+    ///
+    /// assert!((G.evaluate_scalar(1.0) - F.evaluate(&[1.0, 0.5])).abs() < 1e-12);
+    /// ```
+    pub const fn bind<const M: usize, const LEN: usize>(
+        &self,
+        values: &[Option<f64>; NUM_VARIABLES],
+        cross: &'static [CrossFactor<M>],
+    ) -> [Term<M>; LEN] {
+        assert!(
+            M == unbound_count(values),
+            "M must equal the number of unbound variables"
+        );
+        assert!(
+            LEN == self.terms.len(),
+            "array length must equal num_terms()"
+        );
+        assert!(
+            cross.len() == self.bind_cross_len(values),
+            "cross must hold the result of bind_cross()"
+        );
+
+        // Position of each unbound variable in the result, and the bound values as a point
+        let positions = unbound_positions(values);
+        let mut point = [0.0; NUM_VARIABLES];
+        let mut i = 0;
+
+        while i < NUM_VARIABLES {
+            if let Some(value) = values[i] {
+                point[i] = value;
+            }
+            i += 1;
+        }
+
+        let mut out = [Term::new(0.0, [VarFunction::Pow(0); M]); LEN];
+        let terms: &'static [Term<NUM_VARIABLES>] = self.terms;
+        let mut rest = cross;
+        let mut t = 0;
+
+        while t < terms.len() {
+            let term = &terms[t];
+            let mut coeff = term.coeff;
+            let mut functions = [VarFunction::Pow(0); M];
+            let mut kept = 0;
+            let mut i = 0;
+
+            while i < NUM_VARIABLES {
+                match values[i] {
+                    Some(value) => coeff *= term.functions[i].evaluate(value),
+                    None => functions[positions[i]] = term.functions[i],
+                }
+                i += 1;
+            }
+
+            let mut k = 0;
+            while k < term.cross.len() {
+                let factor: &'static CrossFactor<NUM_VARIABLES> = &term.cross[k];
+
+                if is_kept_cross(term, k, values) {
+                    kept += 1;
+                } else if let Some(free_var) = scaled_cross_variable(term, k, values) {
+                    let (function, scale, offset) = match factor {
+                        CrossFactor::Linear(function, weights, _) => {
+                            (function, weights[free_var], bound_argument(factor, values))
+                        }
+                        CrossFactor::Product(function, i, j) => {
+                            let other = if free_var == *i { *j } else { *i };
+                            (function, point[other], 0.0)
+                        }
+                        _ => unreachable!(),
+                    };
+                    functions[positions[free_var]] = VarFunction::Scaled(function, scale, offset);
+                } else {
+                    coeff *= factor.evaluate(&point);
+                }
+                k += 1;
+            }
+
+            let (term_cross, remaining) = rest.split_at(kept);
+            out[t] = Term::with_cross(coeff, functions, term_cross);
+            rest = remaining;
+            t += 1;
+        }
+        out
+    }
+
+    /// Returns the total number of cross-variable factors over all terms.
+    ///
+    /// Use it as the array length for `embed_cross`.
+    pub const fn cross_len(&self) -> usize {
+        let mut len = 0;
+        let mut t = 0;
+
+        while t < self.terms.len() {
+            len += self.terms[t].cross.len();
+            t += 1;
+        }
+        len
+    }
+
+    /// Returns the cross-variable factors of all terms, in order, re-indexed for `embed`.
+    ///
+    /// Panics (at compile time, in a const context) if `LEN` differs from `cross_len()` or if
+    /// `mapping` is not a valid embedding into `M` variables.
+    pub const fn embed_cross<const M: usize, const LEN: usize>(
+        &self,
+        mapping: &[usize; NUM_VARIABLES],
+    ) -> [CrossFactor<M>; LEN] {
+        assert_embedding::<NUM_VARIABLES, M>(mapping);
+        assert!(
+            LEN == self.cross_len(),
+            "array length must equal cross_len()"
+        );
+
+        let mut out = [CrossFactor::Atan2(0, 0); LEN];
+        let mut next = 0;
+        let mut t = 0;
+
+        while t < self.terms.len() {
+            let cross = self.terms[t].cross;
+            let mut k = 0;
+
+            while k < cross.len() {
+                out[next] = cross[k].embed(mapping);
+                next += 1;
+                k += 1;
+            }
+            t += 1;
+        }
+        out
+    }
+
+    /// Embeds the polynomial into `M ≥ NUM_VARIABLES` variables, moving variable `i` to index
+    /// `mapping[i]` and filling the other variables with `Pow(0)` factors.
+    ///
+    /// `cross` must hold the result of `embed_cross` with the same mapping; the embedded
+    /// terms refer to it for their cross-variable factors.
+    ///
+    /// Panics (at compile time, in a const context) if `LEN` differs from `num_terms()`, if
+    /// `cross` has the wrong length, or if `mapping` has an index out of range or a repeated
+    /// index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::VarFunction::*;
+    /// use const_poly::{const_poly, CrossFactor, Polynomial, Term};
+    ///
+    /// // f(x, y) = sin(x)·y, embedded as g(a, y, b, x) = f(x, y)
+    /// const F: Polynomial<2> = const_poly!([1.0, Sin, Identity]);
+    /// const MAPPING: [usize; 2] = [3, 1];
+    /// const CROSS: [CrossFactor<4>; F.cross_len()] = F.embed_cross(&MAPPING);
+    /// const TERMS: [Term<4>; F.num_terms()] = F.embed(&MAPPING, &CROSS);
+    /// const G: Polynomial<4> = Polynomial::new(&TERMS);
+    ///
+    /// assert!(G.evaluate(&[7.0, 2.0, -1.0, 0.5]) == F.evaluate(&[0.5, 2.0]));
+    /// ```
+    pub const fn embed<const M: usize, const LEN: usize>(
+        &self,
+        mapping: &[usize; NUM_VARIABLES],
+        cross: &'static [CrossFactor<M>],
+    ) -> [Term<M>; LEN] {
+        assert_embedding::<NUM_VARIABLES, M>(mapping);
+        assert!(
+            LEN == self.terms.len(),
+            "array length must equal num_terms()"
+        );
+        assert!(
+            cross.len() == self.cross_len(),
+            "cross must hold the result of embed_cross()"
+        );
+
+        let mut out = [Term::new(0.0, [VarFunction::Pow(0); M]); LEN];
+        let mut rest = cross;
+        let mut t = 0;
+
+        while t < self.terms.len() {
+            let term = &self.terms[t];
+            let mut functions = [VarFunction::Pow(0); M];
+            let mut i = 0;
+
+            while i < NUM_VARIABLES {
+                functions[mapping[i]] = term.functions[i];
+                i += 1;
+            }
+
+            let (term_cross, remaining) = rest.split_at(term.cross.len());
+            out[t] = Term::with_cross(term.coeff, functions, term_cross);
+            rest = remaining;
+            t += 1;
+        }
+        out
+    }
+
+    /// Reorders the variables of the polynomial, moving variable `i` to index `order[i]`.
+    ///
+    /// This is `embed` with `M == NUM_VARIABLES`, so `cross` must hold the result of
+    /// `embed_cross` with the same order.
+    ///
+    /// Panics (at compile time, in a const context) if `order` is not a permutation or the
+    /// lengths do not match, as for `embed`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::VarFunction::*;
+    /// use const_poly::{const_poly, CrossFactor, Polynomial, Term};
+    ///
+    /// // f(x, y, z) = x·exp(y)·z², reordered to g(z, x, y)
+    /// const F: Polynomial<3> = const_poly!([1.0, Identity, Exp, Pow(2)]);
+    /// const ORDER: [usize; 3] = [1, 2, 0];
+    /// const CROSS: [CrossFactor<3>; F.cross_len()] = F.embed_cross(&ORDER);
+    /// const TERMS: [Term<3>; F.num_terms()] = F.permute(&ORDER, &CROSS);
+    /// const G: Polynomial<3> = Polynomial::new(&TERMS);
+    ///
+    /// assert!(G.evaluate(&[3.0, 2.0, 1.0]) == F.evaluate(&[2.0, 1.0, 3.0]));
+    /// ```
+    pub const fn permute<const LEN: usize>(
+        &self,
+        order: &[usize; NUM_VARIABLES],
+        cross: &'static [CrossFactor<NUM_VARIABLES>],
+    ) -> [Term<NUM_VARIABLES>; LEN] {
+        self.embed(order, cross)
+    }
+
+    /// Returns the number of nodes emitted by `write_expr_nodes`.
+    pub(crate) const fn expr_body_len(&self) -> usize {
+        let mut len = 1;
+        let mut i = 0;
+
+        while i < self.terms.len() {
+            len += self.terms[i].expr_len() + 1;
+            i += 1;
+        }
+        len
+    }
+
+    /// Writes the sum of the terms as expression nodes starting at `start`, reading
+    /// variable `i` from node `var_nodes[i]`. Returns the index after the last node written,
+    /// which is the root of the polynomial.
+    pub(crate) const fn write_expr_nodes(
+        &self,
+        nodes: &mut [Node],
+        start: usize,
+        var_nodes: &[usize; NUM_VARIABLES],
+    ) -> usize {
+        nodes[start] = Node::Const(0.0);
+        let mut sum = start;
+        let mut next = start + 1;
+        let mut i = 0;
+
+        while i < self.terms.len() {
+            next = self.terms[i].write_expr_nodes(nodes, next, var_nodes);
+            nodes[next] = Node::Add(sum, next - 1);
+            sum = next;
+            next += 1;
+            i += 1;
+        }
+        next
+    }
+}
+
+/// Returns the position of each unbound variable among the variables left free by `values`.
+const fn unbound_positions<const N: usize>(values: &[Option<f64>; N]) -> [usize; N] {
+    let mut positions = [0; N];
+    let mut free = 0;
+    let mut i = 0;
+
+    while i < N {
+        if values[i].is_none() {
+            positions[i] = free;
+            free += 1;
+        }
+        i += 1;
+    }
+    positions
+}
+
+/// Returns the number of variables of a cross-variable factor left free by `values`, and
+/// the last of them. Variables with a zero weight in a `Linear` factor do not count, and a
+/// free variable used twice counts twice.
+const fn free_cross_variables<const N: usize>(
+    factor: &CrossFactor<N>,
+    values: &[Option<f64>; N],
+) -> (usize, usize) {
+    match *factor {
+        CrossFactor::Atan2(i, j) | CrossFactor::Hypot(i, j) | CrossFactor::Product(_, i, j) => {
+            match (values[i], values[j]) {
+                (Some(_), Some(_)) => (0, 0),
+                (None, Some(_)) => (1, i),
+                (Some(_), None) => (1, j),
+                (_, None) => (2, j),
+            }
+        }
+        CrossFactor::Linear(_, weights, _) => {
+            let mut count = 0;
+            let mut last = 0;
+            let mut i = 0;
+
+            while i < N {
+                if values[i].is_none() && weights[i] != 0.0 {
+                    count += 1;
+                    last = i;
+                }
+                i += 1;
+            }
+            (count, last)
+        }
+    }
+}
+
+/// Returns the free variable of cross factor `k` of `term` if `bind` turns the factor into a
+/// `Scaled` factor of it: a `Linear` or `Product` factor with a single free variable whose
+/// own factor is `Pow(0)`, and the first such factor of that variable in the term.
+const fn scaled_cross_variable<const N: usize>(
+    term: &Term<N>,
+    k: usize,
+    values: &[Option<f64>; N],
+) -> Option<usize> {
+    let factor = &term.cross[k];
+    if let CrossFactor::Atan2(..) | CrossFactor::Hypot(..) = factor {
+        return None;
+    }
+
+    let (count, free_var) = free_cross_variables(factor, values);
+    if count != 1 || !matches!(term.functions[free_var], VarFunction::Pow(0)) {
+        return None;
+    }
+
+    let mut earlier = 0;
+    while earlier < k {
+        let other = &term.cross[earlier];
+        if !matches!(other, CrossFactor::Atan2(..) | CrossFactor::Hypot(..))
+            && let (1, other_var) = free_cross_variables(other, values)
+            && other_var == free_var
+        {
+            return None;
+        }
+        earlier += 1;
+    }
+    Some(free_var)
+}
+
+/// Returns whether `bind` keeps cross factor `k` of `term` as a cross-variable factor.
+const fn is_kept_cross<const N: usize>(
+    term: &Term<N>,
+    k: usize,
+    values: &[Option<f64>; N],
+) -> bool {
+    let (count, _) = free_cross_variables(&term.cross[k], values);
+    count > 0 && scaled_cross_variable(term, k, values).is_none()
+}
+
+/// Returns `b + Σ w_i·x_i` over the bound variables of a `Linear` factor.
+const fn bound_argument<const N: usize>(factor: &CrossFactor<N>, values: &[Option<f64>; N]) -> f64 {
+    let CrossFactor::Linear(_, weights, offset) = *factor else {
+        return 0.0;
+    };
+
+    let mut argument = offset;
+    let mut i = 0;
+    while i < N {
+        if let Some(value) = values[i] {
+            argument += weights[i] * value;
+        }
+        i += 1;
+    }
+    argument
+}
+
+/// Rewrites a cross-variable factor kept by `bind` over the `M` free variables, at the
+/// `positions` given by `unbound_positions`, with the bound variables folded in.
+const fn bind_cross_factor<const N: usize, const M: usize>(
+    factor: &CrossFactor<N>,
+    values: &[Option<f64>; N],
+    positions: &[usize; N],
+) -> CrossFactor<M> {
+    match *factor {
+        CrossFactor::Atan2(i, j) | CrossFactor::Hypot(i, j) => {
+            assert!(
+                values[i].is_none() && values[j].is_none(),
+                "bind: Atan2 and Hypot factors need both variables bound or both free"
+            );
+            if let CrossFactor::Atan2(..) = factor {
+                CrossFactor::Atan2(positions[i], positions[j])
+            } else {
+                CrossFactor::Hypot(positions[i], positions[j])
+            }
+        }
+        CrossFactor::Linear(function, weights, _) => {
+            let mut bound_weights = [0.0; M];
+            let mut i = 0;
+
+            while i < N {
+                if values[i].is_none() {
+                    bound_weights[positions[i]] = weights[i];
+                }
+                i += 1;
+            }
+            CrossFactor::Linear(function, bound_weights, bound_argument(factor, values))
+        }
+        CrossFactor::Product(function, i, j) => match (values[i], values[j]) {
+            (Some(value), None) | (None, Some(value)) => {
+                let free_var = if values[i].is_none() { i } else { j };
+                let mut weights = [0.0; M];
+                weights[positions[free_var]] = value;
+                CrossFactor::Linear(function, weights, 0.0)
+            }
+            _ => CrossFactor::Product(function, positions[i], positions[j]),
+        },
+    }
+}
+
+/// Returns the number of `None` entries of `values`, the variables left free by
+/// `Polynomial::bind`.
+pub const fn unbound_count<const NUM_VARIABLES: usize>(
+    values: &[Option<f64>; NUM_VARIABLES],
+) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+
+    while i < NUM_VARIABLES {
+        if values[i].is_none() {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Checks that `mapping` sends each of `N` variables to a distinct index below `M`.
+const fn assert_embedding<const N: usize, const M: usize>(mapping: &[usize; N]) {
+    let mut used = [false; M];
+    let mut i = 0;
+
+    while i < N {
+        assert!(mapping[i] < M, "mapping index out of range");
+        assert!(!used[mapping[i]], "mapping indices must be distinct");
+        used[mapping[i]] = true;
+        i += 1;
+    }
+}
+
+/// Returns the exponent of a power factor (`Identity` or `Pow(n)`), if it is one.
+const fn power_of(function: VarFunction) -> Option<i32> {
+    match function {
+        VarFunction::Identity => Some(1),
+        VarFunction::Pow(n) => Some(n),
+        _ => None,
+    }
+}
+
+/// Adds `value` to the running Neumaier sum `sum + compensation`.
+const fn neumaier_add(sum: f64, compensation: f64, value: f64) -> (f64, f64) {
+    let (total, error) = two_sum(sum, value);
+    (total, compensation + error)
+}
+
+/// Returns the exponent of variable `i` in a term made only of `Pow(n)` (n ≥ 0) and
+/// `Identity` factors.
+const fn nonnegative_power<const N: usize>(term: &Term<N>, i: usize) -> usize {
+    assert!(
+        term.cross.is_empty(),
+        "Pow-only form: cross-variable factors are not supported"
+    );
+    match power_of(term.functions[i]) {
+        Some(n) if n >= 0 => n as usize,
+        _ => panic!("Pow-only form: only Pow(n) with n >= 0 and Identity factors are supported"),
+    }
+}
+
+/// Returns `true` if the exponents of the variables before `upto` in `term` equal `prefix`.
+const fn has_prefix<const N: usize>(term: &Term<N>, prefix: &[usize; N], upto: usize) -> bool {
+    let mut u = 0;
+
+    while u < upto {
+        if nonnegative_power(term, u) != prefix[u] {
+            return false;
+        }
+        u += 1;
+    }
+    true
+}
+
+/// Builds the Horner scheme of the terms whose exponents of the variables before `var`
+/// equal `prefix`, factoring on `var` and recursing on the following variables.
+///
+/// Nodes are appended from index `next`, and only counted unless `write` is set. Returns
+/// the index of the root node and the next free index.
+const fn horner_nodes<const N: usize>(
+    terms: &[Term<N>],
+    var: usize,
+    prefix: &mut [usize; N],
+    nodes: &mut [HornerNode],
+    mut next: usize,
+    write: bool,
+) -> (usize, usize) {
+    if var == N {
+        // All exponents fixed: the like terms collapse into a single coefficient
+        let mut sum = 0.0;
+        let mut t = 0;
+        while t < terms.len() {
+            if has_prefix(&terms[t], prefix, N) {
+                sum += terms[t].coeff;
+            }
+            t += 1;
+        }
+        if write {
+            nodes[next] = HornerNode::Const(sum);
+        }
+        return (next, next + 1);
+    }
+
+    // Walk the distinct exponents of `var` in descending order:
+    // acc = acc · x^(previous - exponent) + child(exponent)
+    let mut root = None;
+    let mut upper = None;
+    let mut previous = 0;
+
+    loop {
+        let mut exponent = None;
+        let mut t = 0;
+        while t < terms.len() {
+            if has_prefix(&terms[t], prefix, var) {
+                let power = nonnegative_power(&terms[t], var);
+                let below = match upper {
+                    Some(bound) => power < bound,
+                    None => true,
+                };
+                let larger = match exponent {
+                    Some(current) => power > current,
+                    None => true,
+                };
+                if below && larger {
+                    exponent = Some(power);
+                }
+            }
+            t += 1;
+        }
+
+        let Some(exponent) = exponent else {
+            break;
+        };
+        prefix[var] = exponent;
+        let (child, after) = horner_nodes(terms, var + 1, prefix, nodes, next, write);
+        next = after;
+
+        root = match root {
+            None => Some(child),
+            Some(acc) => {
+                if write {
+                    nodes[next] = HornerNode::MulAdd(acc, var, (previous - exponent) as i32, child);
+                }
+                next += 1;
+                Some(next - 1)
+            }
+        };
+        previous = exponent;
+        upper = Some(exponent);
+    }
+
+    match root {
+        None => {
+            if write {
+                nodes[next] = HornerNode::Const(0.0);
+            }
+            (next, next + 1)
+        }
+        Some(acc) if previous > 0 => {
+            if write {
+                nodes[next] = HornerNode::MulPow(acc, var, previous as i32);
+            }
+            (next, next + 1)
+        }
+        Some(acc) => (acc, next),
+    }
+}
+
+/// Returns the first term whose function of variable `i` is the same factor as in term `t`.
+const fn find_factor<const N: usize>(terms: &[Term<N>], t: usize, i: usize) -> usize {
+    let function = &terms[t].functions[i];
+    let mut s = 0;
+
+    while s < t {
+        if terms[s].functions[i].same_as(function) {
+            return s;
+        }
+        s += 1;
+    }
+    t
+}
+
+/// Multiplies `factor` into a variable slot currently holding `existing`.
+const fn merge_factor(existing: VarFunction, factor: VarFunction) -> VarFunction {
+    match (power_of(existing), power_of(factor)) {
+        (Some(0), _) => factor,
+        (_, Some(0)) => existing,
+        (Some(a), Some(b)) => VarFunction::Pow(a + b),
+        _ => panic!("compose: two non-power factors of the same variable, use compose_expr_nodes"),
+    }
+}
+
+/// Returns how many terms `term` expands into under the substitutions `subs`.
+const fn composed_term_count<const N: usize, const M: usize>(
+    term: &Term<N>,
+    subs: &[Polynomial<M>; N],
+) -> usize {
+    assert!(
+        term.cross.is_empty(),
+        "compose: cross-variable factors are not supported, use compose_expr_nodes"
+    );
+
+    let mut count: usize = 1;
+    let mut i = 0;
+
+    while i < N {
+        if let Some(p) = power_of(term.functions[i]) {
+            if p > 0 {
+                let expanded = match subs[i].terms.len().checked_pow(p as u32) {
+                    Some(power) => count.checked_mul(power),
+                    None => None,
+                };
+                assert!(
+                    expanded.is_some(),
+                    "compose: the expansion of a term overflows usize at the power of a variable \
+                     with a multi-term substitute"
+                );
+                count = expanded.unwrap();
+            } else if p < 0 {
+                assert!(
+                    subs[i].terms.len() == 1,
+                    "compose: negative powers need a single-term substitute"
+                );
+            }
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Builds the `k`-th term of the expansion of `term` under the substitutions `subs`.
+///
+/// `k` is read as a mixed-radix number whose digits select one substituted term for each
+/// power factor.
+const fn composed_term<const N: usize, const M: usize>(
+    term: &'static Term<N>,
+    subs: &[Polynomial<M>; N],
+    k: usize,
+) -> Term<M> {
+    let mut coeff = term.coeff;
+    let mut functions = [VarFunction::Pow(0); M];
+    let mut rest = k;
+    let mut i = 0;
+
+    while i < N {
+        let sub_terms = subs[i].terms;
+
+        match power_of(term.functions[i]) {
+            Some(p) if p > 0 => {
+                let mut n = 0;
+                while n < p {
+                    let sub_term = &sub_terms[rest % sub_terms.len()];
+                    rest /= sub_terms.len();
+                    assert!(
+                        sub_term.cross.is_empty(),
+                        "compose: cross-variable factors are not supported, use compose_expr_nodes"
+                    );
+
+                    coeff *= sub_term.coeff;
+                    let mut j = 0;
+                    while j < M {
+                        functions[j] = merge_factor(functions[j], sub_term.functions[j]);
+                        j += 1;
+                    }
+                    n += 1;
+                }
+            }
+            Some(p) if p < 0 => {
+                let sub_term = &sub_terms[0];
+                assert!(
+                    sub_term.cross.is_empty(),
+                    "compose: cross-variable factors are not supported, use compose_expr_nodes"
+                );
+
+                coeff *= static_powi(sub_term.coeff, p);
+                let mut j = 0;
+                while j < M {
+                    let Some(q) = power_of(sub_term.functions[j]) else {
+                        panic!("compose: negative powers need a pure power substitute");
+                    };
+                    functions[j] = merge_factor(functions[j], VarFunction::Pow(q * p));
+                    j += 1;
+                }
+            }
+            Some(_) => {}
+            None => {
+                let (var, scale, offset) = affine_form(&subs[i]);
+                match var {
+                    Some(j) => {
+                        let scaled = VarFunction::Scaled(&term.functions[i], scale, offset);
+                        functions[j] = merge_factor(functions[j], scaled);
+                    }
+                    None => coeff *= term.functions[i].evaluate(offset),
+                }
+            }
+        }
+        i += 1;
+    }
+
+    Term::new(coeff, functions)
+}
+
+/// Reads `sub` as `scale * y_j + offset`, returning `(Some(j), scale, offset)`, or
+/// `(None, 0.0, offset)` for a constant polynomial.
+///
+/// Panics if `sub` is not affine in a single variable.
+const fn affine_form<const M: usize>(sub: &Polynomial<M>) -> (Option<usize>, f64, f64) {
+    let mut var: Option<usize> = None;
+    let mut scale = 0.0;
+    let mut offset = 0.0;
+    let mut t = 0;
+
+    while t < sub.terms.len() {
+        let term = &sub.terms[t];
+        assert!(
+            term.cross.is_empty(),
+            "compose: cross-variable factors are not supported, use compose_expr_nodes"
+        );
+
+        let mut linear_var: Option<usize> = None;
+        let mut j = 0;
+        while j < M {
+            match power_of(term.functions[j]) {
+                Some(0) => {}
+                Some(1) if linear_var.is_none() => linear_var = Some(j),
+                _ => panic!(
+                    "compose: functions other than powers need an affine substitute, use compose_expr_nodes"
+                ),
+            }
+            j += 1;
+        }
+
+        match (linear_var, var) {
+            (None, _) => offset += term.coeff,
+            (Some(j), None) => {
+                var = Some(j);
+                scale += term.coeff;
+            }
+            (Some(j), Some(previous)) => {
+                assert!(
+                    j == previous,
+                    "compose: functions other than powers need a substitute in a single variable, use compose_expr_nodes"
+                );
+                scale += term.coeff;
+            }
+        }
+        t += 1;
+    }
+
+    (var, scale, offset)
 }
 
 /// --- Special case: Single-variable ---
@@ -93,4 +1399,59 @@ impl Polynomial<1> {
     pub const fn evaluate_scalar(&self, x: f64) -> f64 {
         self.evaluate(&[x])
     }
+
+    /// Returns the highest power of a polynomial made only of `Pow(n)` (n ≥ 0) and
+    /// `Identity` terms, i.e. the `DEG` of its `DensePoly` form.
+    ///
+    /// Panics (at compile time, in a const context) if a term holds any other function, a
+    /// negative power or a cross-variable factor.
+    pub const fn degree(&self) -> usize {
+        let mut degree = 0;
+        let mut t = 0;
+
+        while t < self.terms.len() {
+            let power = nonnegative_power(&self.terms[t], 0);
+            if power > degree {
+                degree = power;
+            }
+            t += 1;
+        }
+        degree
+    }
+
+    /// Collects the terms of a `Pow`-only polynomial into dense coefficients, constant
+    /// coefficient first, adding up terms of the same power.
+    ///
+    /// Panics (at compile time, in a const context) if `LEN` differs from `degree() + 1`, or
+    /// under the same conditions as `degree`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::VarFunction::*;
+    /// use const_poly::{const_poly, DensePoly, Polynomial};
+    ///
+    /// // f(x) = 2x³ - x + 4
+    /// const F: Polynomial<1> = const_poly!({[2.0, Pow(3)], [-1.0, Identity], [4.0, Pow(0)]});
+    /// const COEFFS: [f64; F.degree() + 1] = F.to_dense_coeffs();
+    /// const P: DensePoly<3> = DensePoly::new(&COEFFS);
+    ///
+    /// assert_eq!(COEFFS, [4.0, -1.0, 0.0, 2.0]);
+    /// assert_eq!(P.evaluate(1.5), F.evaluate_scalar(1.5));
+    /// ```
+    pub const fn to_dense_coeffs<const LEN: usize>(&self) -> [f64; LEN] {
+        assert!(
+            LEN == self.degree() + 1,
+            "array length must equal degree() + 1"
+        );
+
+        let mut coeffs = [0.0; LEN];
+        let mut t = 0;
+
+        while t < self.terms.len() {
+            coeffs[nonnegative_power(&self.terms[t], 0)] += self.terms[t].coeff;
+            t += 1;
+        }
+        coeffs
+    }
 }
@@ -0,0 +1,159 @@
+use crate::term::VarFunction;
+
+/// Represents a single term of a sparse polynomial.
+///
+/// Only the variables that actually appear in the term are stored, as
+/// `(variable index, VarFunction)` pairs; every other variable contributes a factor of 1.
+///
+/// For example:
+/// ```
+/// use const_poly::{SparseTerm, VarFunction::*};
+/// const TERM: SparseTerm = SparseTerm::new(2.0, &[(3, Sin), (17, Pow(2))]);
+/// ```
+/// represents the term `2 * sin(x_3) * (x_17)^2`.
+#[derive(Clone, Copy)]
+pub struct SparseTerm {
+    coeff: f64,
+    factors: &'static [(usize, VarFunction)],
+}
+
+impl SparseTerm {
+    /// Creates a new `SparseTerm` with the specified coefficient and factors.
+    ///
+    /// # Parameters
+    ///
+    /// - `coefficient`: The scalar multiplier for the term.
+    /// - `factors`: `(variable index, VarFunction)` pairs for the variables in the term.
+    ///
+    /// # Returns
+    ///
+    /// A new `SparseTerm` instance.
+    pub const fn new(coefficient: f64, factors: &'static [(usize, VarFunction)]) -> Self {
+        Self {
+            coeff: coefficient,
+            factors,
+        }
+    }
+
+    /// Evaluates the term for the given variables.
+    ///
+    /// Applies each factor's function to its variable, then multiplies all results
+    /// together with the coefficient.
+    ///
+    /// # Parameters
+    ///
+    /// - `vars`: An array of variables of length `NUM_VARIABLES`.
+    ///
+    /// # Returns
+    ///
+    /// The floating-point result of evaluating the term.
+    pub const fn evaluate<const NUM_VARIABLES: usize>(&self, vars: &[f64; NUM_VARIABLES]) -> f64 {
+        let mut result = self.coeff;
+        let mut i = 0;
+
+        while i < self.factors.len() {
+            let (index, function) = self.factors[i];
+            result *= function.evaluate(vars[index]);
+            i += 1;
+        }
+
+        result
+    }
+}
+
+/// Represents a polynomial over NUM_VARIABLES variables whose terms each involve only a few
+/// of them.
+///
+/// Evaluation cost depends on the number of factors actually present, not on
+/// `NUM_VARIABLES`.
+///
+/// # Example
+///
+/// ```
+/// use const_poly::VarFunction::*;
+/// use const_poly::{sparse_poly, SparsePolynomial};
+///
+/// // f(x_0, ..., x_199) = 2 * sin(x_3) * (x_17)^2 - x_150
+/// const POLY: SparsePolynomial<200> = sparse_poly!({
+///     [2.0, x3: Sin, x17: Pow(2)],
+///     [-1.0, x150: Identity]
+/// });
+///
+/// let mut vars = [0.0; 200];
+/// vars[3] = 1.57079632679;
+/// vars[17] = 3.0;
+/// vars[150] = 4.0;
+/// assert!((POLY.evaluate(&vars) - 14.0).abs() < 1e-6);
+/// ```
+pub struct SparsePolynomial<const NUM_VARIABLES: usize> {
+    terms: &'static [SparseTerm],
+}
+
+impl<const NUM_VARIABLES: usize> SparsePolynomial<NUM_VARIABLES> {
+    /// Creates a new `SparsePolynomial` from an array of sparse terms.
+    ///
+    /// Panics (at compile time, in a const context) if a factor refers to a variable index
+    /// of `NUM_VARIABLES` or more.
+    pub const fn new(terms: &'static [SparseTerm]) -> Self {
+        let mut t = 0;
+
+        while t < terms.len() {
+            let factors = terms[t].factors;
+            let mut i = 0;
+
+            while i < factors.len() {
+                assert!(factors[i].0 < NUM_VARIABLES, "variable index out of range");
+                i += 1;
+            }
+            t += 1;
+        }
+
+        Self { terms }
+    }
+
+    /// Evaluates the polynomial at the given variable values.
+    ///
+    /// This computes the sum of the evaluations of all constituent terms.
+    ///
+    /// # Parameters
+    ///
+    /// - `vars`: An array of `NUM_VARIABLES` floating-point values representing the variables.
+    ///
+    /// # Returns
+    ///
+    /// The floating-point result of evaluating the polynomial.
+    pub const fn evaluate(&self, vars: &[f64; NUM_VARIABLES]) -> f64 {
+        let mut sum = 0.0;
+        let mut i = 0;
+
+        while i < self.terms.len() {
+            sum += self.terms[i].evaluate(vars);
+            i += 1;
+        }
+        sum
+    }
+}
+
+/// Parses a variable name of the form `x<index>`, such as `x17`, into its index.
+///
+/// Used by `sparse_poly!`; panics (at compile time, in a const context) on any other name.
+pub const fn var_index(name: &str) -> usize {
+    let bytes = name.as_bytes();
+    assert!(
+        bytes.len() > 1 && bytes[0] == b'x',
+        "variable names must be of the form x<index>"
+    );
+
+    let mut index = 0;
+    let mut i = 1;
+
+    while i < bytes.len() {
+        assert!(
+            bytes[i].is_ascii_digit(),
+            "variable names must be of the form x<index>"
+        );
+        index = index * 10 + (bytes[i] - b'0') as usize;
+        i += 1;
+    }
+    index
+}
@@ -1,3 +1,6 @@
+use crate::correctly_rounded::{cos_cr, exp_cr, ln_cr, sin_cr, sqrt_cr};
+use crate::double_double::*;
+use crate::expr::Node;
 use crate::function_approximations::*;
 
 /// Enum representing the mathematical function applied to a variable in a term.
@@ -17,19 +20,432 @@ use crate::function_approximations::*;
 /// - `Arctan`: arctangent function
 /// - `Sinh`: hyperbolic sine function
 /// - `Cosh`: hyperbolic cosine function
+/// - `Arcsin`: arcsine function
+/// - `Arccos`: arccosine function
+/// - `Asinh`: inverse hyperbolic sine function
+/// - `Acosh`: inverse hyperbolic cosine function
+/// - `Atanh`: inverse hyperbolic tangent function
+/// - `PowFrac(p, q)`: rational power function `x^(p/q)`, real for odd roots of negative `x`
+/// - `PowF(y)`: real power function `x^y`
+/// - `Cbrt`: cube root function
+/// - `Erf`: error function
+/// - `Erfc`: complementary error function
+/// - `NormPdf`: standard normal probability density function
+/// - `NormCdf`: standard normal cumulative distribution function
+/// - `Probit`: inverse standard normal cumulative distribution function
+/// - `Gamma`: gamma function
+/// - `LnGamma`: natural logarithm of the absolute value of the gamma function
+/// - `BesselJ0`, `BesselJ1`: Bessel functions of the first kind of order 0 and 1
+/// - `BesselY0`, `BesselY1`: Bessel functions of the second kind of order 0 and 1
+/// - `BesselI0`: modified Bessel function of the first kind of order 0
+/// - `BesselK0`: modified Bessel function of the second kind of order 0
+/// - `Sigmoid`: logistic sigmoid function
+/// - `Softplus`: softplus function `ln(1 + e^x)`
+/// - `Relu`: rectified linear unit `max(x, 0)`
+/// - `LeakyRelu(alpha)`: leaky rectified linear unit with slope `alpha` for negative `x`
+/// - `Gelu`: Gaussian error linear unit `x * Φ(x)`
+/// - `Swish`: swish (SiLU) function `x * sigmoid(x)`
+/// - `Abs`: absolute value
+/// - `Sign`: sign function, `0` at zero
+/// - `Floor`: largest integer not greater than `x`
+/// - `Ceil`: smallest integer not less than `x`
+/// - `Round`: nearest integer, halfway cases away from zero
+/// - `Heaviside`: unit step function, `0.5` at zero
+/// - `Clamp(lo, hi)`: `x` restricted to the interval `[lo, hi]`
+/// - `Sec`: secant function
+/// - `Csc`: cosecant function
+/// - `Cot`: cotangent function
+/// - `SinDeg`: sine of `x` in degrees
+/// - `CosDeg`: cosine of `x` in degrees
+/// - `TanDeg`: tangent of `x` in degrees
+/// - `LambertW`: principal branch of the Lambert W function
+/// - `EllipticK`: complete elliptic integral of the first kind, with parameter `m = x`
+/// - `EllipticE`: complete elliptic integral of the second kind, with parameter `m = x`
+/// - `SinCr`, `CosCr`, `ExpCr`, `LnCr`, `SqrtCr`: sine, cosine, exponential, natural logarithm
+///   and square root, correctly rounded whether or not the `correctly-rounded` feature is on
+/// - `Scaled(f, a, b)`: any of the above applied to the affine argument `a * x + b`,
+///   e.g. `Scaled(&Sin, 2.0 * PI * freq, phase)`
+///
+/// NaN inputs propagate through every variant.
 #[derive(Clone, Copy)]
 pub enum VarFunction {
-    Identity, // x
-    Pow(i32), // x^n
-    Sin,      // sin(x)
-    Cos,      // cos(x)
-    Tan,      // tan(x)
-    Exp,      // exp(x)
-    Ln,       // ln(x)
-    Sqrt,     // sqrt(x)
-    Arctan,   // arctan(x)
-    Sinh,     // sinh(x)
-    Cosh,     // cosh(x)
+    Identity,          // x
+    Pow(i32),          // x^n
+    Sin,               // sin(x)
+    Cos,               // cos(x)
+    Tan,               // tan(x)
+    Exp,               // exp(x)
+    Ln,                // ln(x)
+    Sqrt,              // sqrt(x)
+    Arctan,            // arctan(x)
+    Sinh,              // sinh(x)
+    Cosh,              // cosh(x)
+    Arcsin,            // asin(x)
+    Arccos,            // acos(x)
+    Asinh,             // asinh(x)
+    Acosh,             // acosh(x)
+    Atanh,             // atanh(x)
+    PowFrac(i32, u32), // x^(p/q)
+    PowF(f64),         // x^y
+    Cbrt,              // cbrt(x)
+    Erf,               // erf(x)
+    Erfc,              // erfc(x)
+    NormPdf,           // φ(x)
+    NormCdf,           // Φ(x)
+    Probit,            // Φ⁻¹(x)
+    Gamma,             // Γ(x)
+    LnGamma,           // ln|Γ(x)|
+    BesselJ0,          // J₀(x)
+    BesselJ1,          // J₁(x)
+    BesselY0,          // Y₀(x)
+    BesselY1,          // Y₁(x)
+    BesselI0,          // I₀(x)
+    BesselK0,          // K₀(x)
+    Sigmoid,           // 1 / (1 + e^(-x))
+    Softplus,          // ln(1 + e^x)
+    Relu,              // max(x, 0)
+    LeakyRelu(f64),    // x if x ≥ 0, else alpha * x
+    Gelu,              // x * Φ(x)
+    Swish,             // x * sigmoid(x)
+    Abs,               // |x|
+    Sign,              // sign(x)
+    Floor,             // ⌊x⌋
+    Ceil,              // ⌈x⌉
+    Round,             // round(x)
+    Heaviside,         // H(x)
+    Clamp(f64, f64),   // min(max(x, lo), hi)
+    Sec,               // sec(x)
+    Csc,               // csc(x)
+    Cot,               // cot(x)
+    SinDeg,            // sin(x°)
+    CosDeg,            // cos(x°)
+    TanDeg,            // tan(x°)
+    LambertW,          // W₀(x)
+    EllipticK,         // K(x)
+    EllipticE,         // E(x)
+    SinCr,             // sin(x), correctly rounded
+    CosCr,             // cos(x), correctly rounded
+    ExpCr,             // exp(x), correctly rounded
+    LnCr,              // ln(x), correctly rounded
+    SqrtCr,            // sqrt(x), correctly rounded
+    // f(a * x + b)
+    Scaled(&'static VarFunction, f64, f64),
+}
+
+impl VarFunction {
+    /// Applies the function to a single value `x`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::VarFunction::*;
+    /// const VAL: f64 = Scaled(&Exp, -0.5, 0.0).evaluate(2.0); // exp(-2 / 2)
+    /// assert!((VAL - 0.36787944117144233).abs() < 1e-12);
+    /// ```
+    pub const fn evaluate(&self, x: f64) -> f64 {
+        match *self {
+            VarFunction::Identity => x,
+            VarFunction::Pow(exp) => static_powi(x, exp),
+            VarFunction::Sin => sin_approx(x),
+            VarFunction::Cos => cos_approx(x),
+            VarFunction::Tan => tan_approx(x),
+            VarFunction::Exp => exp_approx(x),
+            VarFunction::Ln => ln_approx(x),
+            VarFunction::Sqrt => sqrt_approx(x),
+            VarFunction::Arctan => arctan_approx(x),
+            VarFunction::Sinh => sinh_approx(x),
+            VarFunction::Cosh => cosh_approx(x),
+            VarFunction::Arcsin => arcsin_approx(x),
+            VarFunction::Arccos => arccos_approx(x),
+            VarFunction::Asinh => asinh_approx(x),
+            VarFunction::Acosh => acosh_approx(x),
+            VarFunction::Atanh => atanh_approx(x),
+            VarFunction::PowFrac(p, q) => pow_frac_approx(x, p, q),
+            VarFunction::PowF(y) => powf_approx(x, y),
+            VarFunction::Cbrt => cbrt_approx(x),
+            VarFunction::Erf => erf_approx(x),
+            VarFunction::Erfc => erfc_approx(x),
+            VarFunction::NormPdf => norm_pdf_approx(x),
+            VarFunction::NormCdf => norm_cdf_approx(x),
+            VarFunction::Probit => probit_approx(x),
+            VarFunction::Gamma => gamma_approx(x),
+            VarFunction::LnGamma => lgamma_approx(x),
+            VarFunction::BesselJ0 => bessel_j0_approx(x),
+            VarFunction::BesselJ1 => bessel_j1_approx(x),
+            VarFunction::BesselY0 => bessel_y0_approx(x),
+            VarFunction::BesselY1 => bessel_y1_approx(x),
+            VarFunction::BesselI0 => bessel_i0_approx(x),
+            VarFunction::BesselK0 => bessel_k0_approx(x),
+            VarFunction::Sigmoid => sigmoid_approx(x),
+            VarFunction::Softplus => softplus_approx(x),
+            VarFunction::Relu => relu(x),
+            VarFunction::LeakyRelu(alpha) => leaky_relu(x, alpha),
+            VarFunction::Gelu => gelu_approx(x),
+            VarFunction::Swish => swish_approx(x),
+            VarFunction::Abs => absolute(x),
+            VarFunction::Sign => sign(x),
+            VarFunction::Floor => floor(x),
+            VarFunction::Ceil => ceil(x),
+            VarFunction::Round => round(x),
+            VarFunction::Heaviside => heaviside(x),
+            VarFunction::Clamp(lo, hi) => clamp(x, lo, hi),
+            VarFunction::Sec => sec_approx(x),
+            VarFunction::Csc => csc_approx(x),
+            VarFunction::Cot => cot_approx(x),
+            VarFunction::SinDeg => sin_deg_approx(x),
+            VarFunction::CosDeg => cos_deg_approx(x),
+            VarFunction::TanDeg => tan_deg_approx(x),
+            VarFunction::LambertW => lambert_w0_approx(x),
+            VarFunction::EllipticK => elliptic_k_approx(x),
+            VarFunction::EllipticE => elliptic_e_approx(x),
+            VarFunction::SinCr => sin_cr(x),
+            VarFunction::CosCr => cos_cr(x),
+            VarFunction::ExpCr => exp_cr(x),
+            VarFunction::LnCr => ln_cr(x),
+            VarFunction::SqrtCr => sqrt_cr(x),
+            VarFunction::Scaled(function, scale, offset) => function.evaluate(scale * x + offset),
+        }
+    }
+
+    /// Applies the function to a double-double value `x`.
+    ///
+    /// `Identity`, `Pow`, `Sin`, `Cos`, `Tan`, `Exp`, `Ln`, `Sqrt`, `Arctan`, `Sinh`, `Cosh`
+    /// and `Scaled` are computed in double-double; the other variants evaluate their f64
+    /// approximation at `x` rounded to f64.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::DoubleDouble;
+    /// use const_poly::VarFunction::*;
+    ///
+    /// const VAL: DoubleDouble = Exp.evaluate_dd(DoubleDouble::from_f64(1.0));
+    /// assert_eq!(VAL.hi(), core::f64::consts::E);
+    /// assert!((VAL.lo() - 1.4456468917292502e-16).abs() < 1e-31);
+    /// ```
+    pub const fn evaluate_dd(&self, x: DoubleDouble) -> DoubleDouble {
+        match *self {
+            VarFunction::Identity => x,
+            VarFunction::Pow(exp) => powi_dd(x, exp),
+            VarFunction::Sin => sin_dd(x),
+            VarFunction::Cos => cos_dd(x),
+            VarFunction::Tan => tan_dd(x),
+            VarFunction::Exp => exp_dd(x),
+            VarFunction::Ln => ln_dd(x),
+            VarFunction::Sqrt => sqrt_dd(x),
+            VarFunction::Arctan => arctan_dd(x),
+            VarFunction::Sinh => sinh_dd(x),
+            VarFunction::Cosh => cosh_dd(x),
+            VarFunction::Scaled(function, scale, offset) => function.evaluate_dd(
+                x.mul(DoubleDouble::from_f64(scale))
+                    .add(DoubleDouble::from_f64(offset)),
+            ),
+            function => DoubleDouble::from_f64(function.evaluate(x.to_f64())),
+        }
+    }
+
+    /// Returns `true` if both functions are the same variant with bitwise equal parameters,
+    /// i.e. if they always produce the same value.
+    pub(crate) const fn same_as(&self, other: &VarFunction) -> bool {
+        // Exhaustive on `self`, so that a new variant cannot be left out of the comparison
+        match *self {
+            VarFunction::Identity => matches!(other, VarFunction::Identity),
+            VarFunction::Pow(a) => matches!(*other, VarFunction::Pow(b) if a == b),
+            VarFunction::Sin => matches!(other, VarFunction::Sin),
+            VarFunction::Cos => matches!(other, VarFunction::Cos),
+            VarFunction::Tan => matches!(other, VarFunction::Tan),
+            VarFunction::Exp => matches!(other, VarFunction::Exp),
+            VarFunction::Ln => matches!(other, VarFunction::Ln),
+            VarFunction::Sqrt => matches!(other, VarFunction::Sqrt),
+            VarFunction::Arctan => matches!(other, VarFunction::Arctan),
+            VarFunction::Sinh => matches!(other, VarFunction::Sinh),
+            VarFunction::Cosh => matches!(other, VarFunction::Cosh),
+            VarFunction::Arcsin => matches!(other, VarFunction::Arcsin),
+            VarFunction::Arccos => matches!(other, VarFunction::Arccos),
+            VarFunction::Asinh => matches!(other, VarFunction::Asinh),
+            VarFunction::Acosh => matches!(other, VarFunction::Acosh),
+            VarFunction::Atanh => matches!(other, VarFunction::Atanh),
+            VarFunction::PowFrac(p1, q1) => {
+                matches!(*other, VarFunction::PowFrac(p2, q2) if p1 == p2 && q1 == q2)
+            }
+            VarFunction::PowF(a) => {
+                matches!(*other, VarFunction::PowF(b) if a.to_bits() == b.to_bits())
+            }
+            VarFunction::Cbrt => matches!(other, VarFunction::Cbrt),
+            VarFunction::Erf => matches!(other, VarFunction::Erf),
+            VarFunction::Erfc => matches!(other, VarFunction::Erfc),
+            VarFunction::NormPdf => matches!(other, VarFunction::NormPdf),
+            VarFunction::NormCdf => matches!(other, VarFunction::NormCdf),
+            VarFunction::Probit => matches!(other, VarFunction::Probit),
+            VarFunction::Gamma => matches!(other, VarFunction::Gamma),
+            VarFunction::LnGamma => matches!(other, VarFunction::LnGamma),
+            VarFunction::BesselJ0 => matches!(other, VarFunction::BesselJ0),
+            VarFunction::BesselJ1 => matches!(other, VarFunction::BesselJ1),
+            VarFunction::BesselY0 => matches!(other, VarFunction::BesselY0),
+            VarFunction::BesselY1 => matches!(other, VarFunction::BesselY1),
+            VarFunction::BesselI0 => matches!(other, VarFunction::BesselI0),
+            VarFunction::BesselK0 => matches!(other, VarFunction::BesselK0),
+            VarFunction::Sigmoid => matches!(other, VarFunction::Sigmoid),
+            VarFunction::Softplus => matches!(other, VarFunction::Softplus),
+            VarFunction::Relu => matches!(other, VarFunction::Relu),
+            VarFunction::LeakyRelu(a) => {
+                matches!(*other, VarFunction::LeakyRelu(b) if a.to_bits() == b.to_bits())
+            }
+            VarFunction::Gelu => matches!(other, VarFunction::Gelu),
+            VarFunction::Swish => matches!(other, VarFunction::Swish),
+            VarFunction::Abs => matches!(other, VarFunction::Abs),
+            VarFunction::Sign => matches!(other, VarFunction::Sign),
+            VarFunction::Floor => matches!(other, VarFunction::Floor),
+            VarFunction::Ceil => matches!(other, VarFunction::Ceil),
+            VarFunction::Round => matches!(other, VarFunction::Round),
+            VarFunction::Heaviside => matches!(other, VarFunction::Heaviside),
+            VarFunction::Clamp(lo1, hi1) => matches!(
+                *other,
+                VarFunction::Clamp(lo2, hi2)
+                    if lo1.to_bits() == lo2.to_bits() && hi1.to_bits() == hi2.to_bits()
+            ),
+            VarFunction::Sec => matches!(other, VarFunction::Sec),
+            VarFunction::Csc => matches!(other, VarFunction::Csc),
+            VarFunction::Cot => matches!(other, VarFunction::Cot),
+            VarFunction::SinDeg => matches!(other, VarFunction::SinDeg),
+            VarFunction::CosDeg => matches!(other, VarFunction::CosDeg),
+            VarFunction::TanDeg => matches!(other, VarFunction::TanDeg),
+            VarFunction::LambertW => matches!(other, VarFunction::LambertW),
+            VarFunction::EllipticK => matches!(other, VarFunction::EllipticK),
+            VarFunction::EllipticE => matches!(other, VarFunction::EllipticE),
+            VarFunction::SinCr => matches!(other, VarFunction::SinCr),
+            VarFunction::CosCr => matches!(other, VarFunction::CosCr),
+            VarFunction::ExpCr => matches!(other, VarFunction::ExpCr),
+            VarFunction::LnCr => matches!(other, VarFunction::LnCr),
+            VarFunction::SqrtCr => matches!(other, VarFunction::SqrtCr),
+            VarFunction::Scaled(f1, a1, b1) => matches!(
+                *other,
+                VarFunction::Scaled(f2, a2, b2)
+                    if a1.to_bits() == a2.to_bits() && b1.to_bits() == b2.to_bits() && f1.same_as(f2)
+            ),
+        }
+    }
+}
+
+/// Enum representing a factor of a term that depends on several variables at once.
+///
+/// Variable indices refer to positions in the `vars` array passed to `evaluate`.
+///
+/// Variants:
+/// - `Atan2(i, j)`: four-quadrant arctangent `atan2(x_i, x_j)`
+/// - `Hypot(i, j)`: Euclidean norm `sqrt(x_i² + x_j²)`
+/// - `Linear(f, w, b)`: a `VarFunction` applied to the linear combination `w · x + b`
+/// - `Product(f, i, j)`: a `VarFunction` applied to the product `x_i * x_j`
+#[derive(Clone, Copy)]
+pub enum CrossFactor<const NUM_VARIABLES: usize> {
+    Atan2(usize, usize),                            // atan2(x_i, x_j)
+    Hypot(usize, usize),                            // sqrt(x_i² + x_j²)
+    Linear(VarFunction, [f64; NUM_VARIABLES], f64), // f(w · x + b)
+    Product(VarFunction, usize, usize),             // f(x_i * x_j)
+}
+
+impl<const NUM_VARIABLES: usize> CrossFactor<NUM_VARIABLES> {
+    /// Evaluates the factor for the given variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::{CrossFactor::*, VarFunction::*};
+    /// const VAL: f64 = Linear::<2>(Sin, [1.0, 1.0], 0.0).evaluate(&[0.5, 1.0]); // sin(0.5 + 1)
+    /// assert!((VAL - 1.5f64.sin()).abs() < 1e-9);
+    /// ```
+    pub const fn evaluate(&self, vars: &[f64; NUM_VARIABLES]) -> f64 {
+        match *self {
+            CrossFactor::Atan2(i, j) => atan2_approx(vars[i], vars[j]),
+            CrossFactor::Hypot(i, j) => hypot_approx(vars[i], vars[j]),
+            CrossFactor::Linear(function, weights, offset) => {
+                let mut argument = offset;
+                let mut i = 0;
+
+                while i < NUM_VARIABLES {
+                    argument += weights[i] * vars[i];
+                    i += 1;
+                }
+
+                function.evaluate(argument)
+            }
+            CrossFactor::Product(function, i, j) => function.evaluate(vars[i] * vars[j]),
+        }
+    }
+
+    /// Returns the same factor over `M` variables, with variable `i` moved to index
+    /// `mapping[i]`.
+    pub(crate) const fn embed<const M: usize>(
+        &self,
+        mapping: &[usize; NUM_VARIABLES],
+    ) -> CrossFactor<M> {
+        match *self {
+            CrossFactor::Atan2(i, j) => CrossFactor::Atan2(mapping[i], mapping[j]),
+            CrossFactor::Hypot(i, j) => CrossFactor::Hypot(mapping[i], mapping[j]),
+            CrossFactor::Linear(function, weights, offset) => {
+                let mut embedded = [0.0; M];
+                let mut i = 0;
+
+                while i < NUM_VARIABLES {
+                    embedded[mapping[i]] = weights[i];
+                    i += 1;
+                }
+
+                CrossFactor::Linear(function, embedded, offset)
+            }
+            CrossFactor::Product(function, i, j) => {
+                CrossFactor::Product(function, mapping[i], mapping[j])
+            }
+        }
+    }
+
+    /// Returns the number of expression nodes emitted by `write_expr_nodes`.
+    pub(crate) const fn expr_len(&self) -> usize {
+        match *self {
+            CrossFactor::Atan2(..) | CrossFactor::Hypot(..) => 1,
+            CrossFactor::Linear(..) => 2 + 3 * NUM_VARIABLES,
+            CrossFactor::Product(..) => 2,
+        }
+    }
+
+    /// Writes the factor as expression nodes starting at `start`, in the same order of
+    /// operations as `evaluate`. Variable `i` is read from node `var_nodes[i]`.
+    /// Returns the index after the last node written, which is the root of the factor.
+    pub(crate) const fn write_expr_nodes(
+        &self,
+        nodes: &mut [Node],
+        start: usize,
+        var_nodes: &[usize; NUM_VARIABLES],
+    ) -> usize {
+        nodes[start] = match *self {
+            CrossFactor::Atan2(i, j) => Node::Atan2(var_nodes[i], var_nodes[j]),
+            CrossFactor::Hypot(i, j) => Node::Hypot(var_nodes[i], var_nodes[j]),
+            CrossFactor::Linear(function, weights, offset) => {
+                nodes[start] = Node::Const(offset);
+                let mut argument = start;
+                let mut next = start + 1;
+                let mut i = 0;
+
+                while i < NUM_VARIABLES {
+                    nodes[next] = Node::Const(weights[i]);
+                    nodes[next + 1] = Node::Mul(next, var_nodes[i]);
+                    nodes[next + 2] = Node::Add(argument, next + 1);
+                    argument = next + 2;
+                    next += 3;
+                    i += 1;
+                }
+
+                nodes[next] = Node::Apply(function, argument);
+                return next + 1;
+            }
+            CrossFactor::Product(function, i, j) => {
+                nodes[start] = Node::Mul(var_nodes[i], var_nodes[j]);
+                nodes[start + 1] = Node::Apply(function, start);
+                return start + 2;
+            }
+        };
+        start + 1
+    }
 }
 
 /// Represents a single term in a polynomial with NUM_VARIABLES variables.
@@ -43,10 +459,14 @@ pub enum VarFunction {
 /// const TERM: Term<2> = Term::new(3.0, [Sin, Pow(2)]);
 /// ```
 /// represents the term `3 * sin(x_0) * (x_1)^2`.
+///
+/// A term may also carry `CrossFactor`s that combine several variables, see
+/// `Term::with_cross`.
 #[derive(Copy, Clone)]
 pub struct Term<const NUM_VARIABLES: usize> {
-    coeff: f64,
-    functions: [VarFunction; NUM_VARIABLES],
+    pub(crate) coeff: f64,
+    pub(crate) functions: [VarFunction; NUM_VARIABLES],
+    pub(crate) cross: &'static [CrossFactor<NUM_VARIABLES>],
 }
 
 impl<const NUM_VARIABLES: usize> Term<NUM_VARIABLES> {
@@ -72,13 +492,46 @@ impl<const NUM_VARIABLES: usize> Term<NUM_VARIABLES> {
         Self {
             coeff: coefficient,
             functions,
+            cross: &[],
+        }
+    }
+
+    /// Creates a new `Term` that is additionally multiplied by cross-variable factors.
+    ///
+    /// # Parameters
+    ///
+    /// - `coefficient`: The scalar multiplier for the term.
+    /// - `functions`: An array of `VarFunction`s, one per variable.
+    /// - `cross`: `CrossFactor`s multiplied into the term after the per-variable functions.
+    ///
+    /// # Returns
+    ///
+    /// A new `Term` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use const_poly::{CrossFactor::*, Term, VarFunction::*};
+    /// const TERM: Term<2> = Term::with_cross(2.0, [Pow(0), Pow(0)], &[Atan2(1, 0), Hypot(0, 1)]);
+    /// ```
+    /// represents the term `2.0 * atan2(x_1, x_0) * sqrt(x_0² + x_1²)`.
+    pub const fn with_cross(
+        coefficient: f64,
+        functions: [VarFunction; NUM_VARIABLES],
+        cross: &'static [CrossFactor<NUM_VARIABLES>],
+    ) -> Self {
+        Self {
+            coeff: coefficient,
+            functions,
+            cross,
         }
     }
 
     /// Evaluates the term for the given variables.
     ///
     /// Applies each function in `functions` to the corresponding variable,
-    /// then multiplies all results together with the coefficient.
+    /// then multiplies all results together with the coefficient and any
+    /// cross-variable factors.
     ///
     /// # Parameters
     ///
@@ -101,24 +554,156 @@ impl<const NUM_VARIABLES: usize> Term<NUM_VARIABLES> {
         let mut i = 0;
 
         while i < NUM_VARIABLES {
-            let value = match self.functions[i] {
-                VarFunction::Identity => vars[i],
-                VarFunction::Pow(exp) => static_powi(vars[i], exp),
-                VarFunction::Sin => sin_approx(vars[i]),
-                VarFunction::Cos => cos_approx(vars[i]),
-                VarFunction::Tan => tan_approx(vars[i]),
-                VarFunction::Exp => exp_approx(vars[i]),
-                VarFunction::Ln => ln_approx(vars[i]),
-                VarFunction::Sqrt => sqrt_approx(vars[i]),
-                VarFunction::Arctan => arctan_approx(vars[i]),
-                VarFunction::Sinh => sinh_approx(vars[i]),
-                VarFunction::Cosh => cosh_approx(vars[i]),
-            };
+            let value = self.functions[i].evaluate(vars[i]);
 
             result *= value;
             i += 1;
         }
 
+        let mut k = 0;
+        while k < self.cross.len() {
+            result *= self.cross[k].evaluate(vars);
+            k += 1;
+        }
+
+        result
+    }
+
+    /// Evaluates the term in double-double arithmetic, see `VarFunction::evaluate_dd`.
+    /// Cross-variable factors are evaluated in f64.
+    pub(crate) const fn evaluate_dd(&self, vars: &[f64; NUM_VARIABLES]) -> DoubleDouble {
+        let mut result = DoubleDouble::from_f64(self.coeff);
+        let mut i = 0;
+
+        while i < NUM_VARIABLES {
+            result = result.mul(self.functions[i].evaluate_dd(DoubleDouble::from_f64(vars[i])));
+            i += 1;
+        }
+
+        let mut k = 0;
+        while k < self.cross.len() {
+            result = result.mul_f64(self.cross[k].evaluate(vars));
+            k += 1;
+        }
+
         result
     }
+
+    /// Evaluates the term like `evaluate`, but keeps the rounding errors of the
+    /// multiplications, returning the unevaluated sum `(value, error)`.
+    ///
+    /// `Pow(n)` factors with `n > 0` are computed by compensated square-and-multiply.
+    pub(crate) const fn evaluate_compensated(&self, vars: &[f64; NUM_VARIABLES]) -> (f64, f64) {
+        let mut value = self.coeff;
+        let mut error = 0.0;
+        let mut i = 0;
+
+        while i < NUM_VARIABLES {
+            // Positive powers keep the rounding errors of their own multiplications too
+            let (factor, factor_error) = match self.functions[i] {
+                VarFunction::Pow(n) if n > 0 => compensated_powi(vars[i], n as u32),
+                function => (function.evaluate(vars[i]), 0.0),
+            };
+
+            (value, error) = compensated_mul(value, error, factor, factor_error);
+            i += 1;
+        }
+
+        let mut k = 0;
+        while k < self.cross.len() {
+            let factor = self.cross[k].evaluate(vars);
+            (value, error) = compensated_mul(value, error, factor, 0.0);
+            k += 1;
+        }
+
+        (value, error)
+    }
+
+    /// Returns the number of expression nodes emitted by `write_expr_nodes`.
+    pub(crate) const fn expr_len(&self) -> usize {
+        // Coefficient, then Apply and Mul for each variable
+        let mut len = 1 + 2 * NUM_VARIABLES;
+        let mut k = 0;
+
+        while k < self.cross.len() {
+            len += self.cross[k].expr_len() + 1;
+            k += 1;
+        }
+
+        len
+    }
+
+    /// Writes the term as expression nodes starting at `start`, in the same order of
+    /// operations as `evaluate`, so that the expression evaluates bit-for-bit identically.
+    /// Variable `i` is read from node `var_nodes[i]`.
+    /// Returns the index after the last node written, which is the root of the term.
+    pub(crate) const fn write_expr_nodes(
+        &self,
+        nodes: &mut [Node],
+        start: usize,
+        var_nodes: &[usize; NUM_VARIABLES],
+    ) -> usize {
+        nodes[start] = Node::Const(self.coeff);
+        let mut product = start;
+        let mut next = start + 1;
+        let mut i = 0;
+
+        while i < NUM_VARIABLES {
+            nodes[next] = Node::Apply(self.functions[i], var_nodes[i]);
+            nodes[next + 1] = Node::Mul(product, next);
+            product = next + 1;
+            next += 2;
+            i += 1;
+        }
+
+        let mut k = 0;
+        while k < self.cross.len() {
+            next = self.cross[k].write_expr_nodes(nodes, next, var_nodes);
+            nodes[next] = Node::Mul(product, next - 1);
+            product = next;
+            next += 1;
+            k += 1;
+        }
+
+        next
+    }
+}
+
+/// Multiplies the unevaluated sum `value + error` by `factor + factor_error`, tracking the
+/// new rounding error. Once the product is infinite or NaN, the error is dropped, so that
+/// overflow gives ±∞ as in plain evaluation instead of NaN.
+const fn compensated_mul(value: f64, error: f64, factor: f64, factor_error: f64) -> (f64, f64) {
+    let (product, rounding) = two_product(value, factor);
+    if !product.is_finite() {
+        return (product, 0.0);
+    }
+    (product, error * factor + value * factor_error + rounding)
+}
+
+/// Computes `x^n` by binary exponentiation, returning the unevaluated sum `(value, error)`
+/// with the rounding errors of every squaring and multiplication kept.
+const fn compensated_powi(x: f64, mut n: u32) -> (f64, f64) {
+    let (mut value, mut error) = (1.0, 0.0);
+    let (mut base, mut base_error) = (x, 0.0);
+
+    while n > 0 {
+        if (n & 1) == 1 {
+            (value, error) = renormalize(compensated_mul(value, error, base, base_error));
+        }
+        n >>= 1;
+        if n > 0 {
+            (base, base_error) = renormalize(compensated_mul(base, base_error, base, base_error));
+        }
+    }
+
+    (value, error)
+}
+
+/// Folds `error` into `value` so that it stays below half an ulp of the value. Squaring
+/// doubles the relative size of the error, which would otherwise grow with the exponent.
+const fn renormalize((value, error): (f64, f64)) -> (f64, f64) {
+    if !value.is_finite() {
+        return (value, 0.0);
+    }
+    two_sum(value, error)
 }
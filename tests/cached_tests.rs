@@ -0,0 +1,83 @@
+use const_poly::CrossFactor::*;
+use const_poly::VarFunction::*;
+use const_poly::{CachedPolynomial, Polynomial, cache_poly, const_poly};
+use static_assertions::const_assert;
+
+#[test]
+fn test_cached_polynomial_matches_polynomial() {
+    const fn inner() -> bool {
+        const POLY: Polynomial<3> = const_poly!({
+            [1.2, Sin, Pow(2), Exp],
+            [-0.8, Sin, Identity, Exp],
+            [2.5, Cos, Pow(2), Scaled(&Exp, 2.0, 0.0)],
+            [0.4, Sin, Pow(3), Scaled(&Exp, 2.0, 0.5)],
+            [-1.1, LeakyRelu(0.1), Identity, Pow(0); Hypot(0, 2)],
+            [0.7, LeakyRelu(0.2), Pow(2), Pow(0); Product(Sin, 0, 1)],
+            [3.0, Cos, Pow(3), Exp]
+        });
+        const CACHED: CachedPolynomial<3, { POLY.factor_count() }> = cache_poly!(POLY);
+
+        // x0: Sin, Cos, LeakyRelu(0.1), LeakyRelu(0.2)
+        // x1: Pow(2), Identity, Pow(3)
+        // x2: Exp, Scaled(&Exp, 2.0, 0.0), Scaled(&Exp, 2.0, 0.5), Pow(0)
+        if POLY.factor_count() != 11 {
+            return false;
+        }
+
+        let points = [-1.3, -0.2, 0.0, 0.7, 2.9];
+        let mut i = 0;
+        while i < points.len() {
+            let mut j = 0;
+            while j < points.len() {
+                let vars = [points[i], points[j], points[i] - points[j]];
+                if CACHED.evaluate(&vars).to_bits() != POLY.evaluate(&vars).to_bits() {
+                    return false;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_cached_polynomial_factor_table() {
+    const fn inner() -> bool {
+        const POLY: Polynomial<2> = const_poly!({
+            [1.0, Sin, Cos],
+            [2.0, Identity, Cos],
+            [3.0, Sin, Sin]
+        });
+        const TABLE: [(usize, const_poly::VarFunction); POLY.factor_count()] = POLY.factor_table();
+        const SLOTS: [[usize; 2]; POLY.num_terms()] = POLY.factor_slots();
+
+        // Factors in order of first appearance: sin(x0), cos(x1), x0, sin(x1)
+        if TABLE.len() != 4
+            || TABLE[0].0 != 0
+            || TABLE[1].0 != 1
+            || TABLE[2].0 != 0
+            || TABLE[3].0 != 1
+            || !matches!(TABLE[0].1, Sin)
+            || !matches!(TABLE[1].1, Cos)
+            || !matches!(TABLE[2].1, Identity)
+            || !matches!(TABLE[3].1, Sin)
+        {
+            return false;
+        }
+
+        SLOTS[0][0] == 0
+            && SLOTS[0][1] == 1
+            && SLOTS[1][0] == 2
+            && SLOTS[1][1] == 1
+            && SLOTS[2][0] == 0
+            && SLOTS[2][1] == 3
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
@@ -0,0 +1,302 @@
+#![allow(clippy::approx_constant)]
+
+use const_poly::VarFunction::*;
+use const_poly::correctly_rounded::*;
+use const_poly::{Polynomial, const_poly, const_poly_fn};
+use static_assertions::const_assert;
+
+// Reference values: the exact result rounded to the nearest f64, computed with mpmath at
+// 1500 bits. Inputs near a rounding midpoint exercise the fixed-point fallback.
+const SIN_CR_REFERENCE: [(f64, f64); 25] = [
+    (-3.523344703336752, 0.37254696591721054),
+    (-6.9830165215099615, -0.6440885836611351),
+    (3.0186894607970753, 0.12259401361762),
+    (-8.551274266649145, -0.7665835159877298),
+    (0.7176400861337839, 0.657608640733832),
+    (-2.6862216617482897, -0.439795531068518),
+    (-8.840021504505863, -0.5519963205992334),
+    (0.1487146637884056, 0.14816710610954803),
+    (1.3331215634452073e-07, 1.3331215634452033e-07),
+    (-35088.47884698493, 0.030494312424640763),
+    (5.264903316589424e-06, 5.2649033165651005e-06),
+    (-5.933557869632517e-07, -5.933557869632169e-07),
+    (-2.6514962743629896e+20, 0.6624257785630208),
+    (2056309389.1750793, -0.921481706668182),
+    (-3.0738268630017375e-07, -3.0738268630016893e-07),
+    (-2.4973557858511205e-07, -2.4973557858510946e-07),
+    (1e-08, 1e-08),
+    (3.141592653589793, 1.2246467991473532e-16),
+    (1.5707963267948966, 1.0),
+    (1e+22, -0.8522008497671888),
+    (1e+300, -0.8178819121159085),
+    (1.7976931348623157e+308, 0.004961954789184062),
+    (5.319372648326541e+255, 1.0),
+    // Within 2^-98 of a rounding midpoint
+    (1.440887539831872e-06, 1.4408875398313733e-06),
+    (1.435564141243101e-06, 1.4355641412426077e-06),
+];
+const COS_CR_REFERENCE: [(f64, f64); 24] = [
+    (-4.207814273366475, -0.4834351910192475),
+    (-7.114898332851249, 0.6736106751101788),
+    (-7.644155238432633, 0.20829010617861252),
+    (-3.830363517961313, -0.7720278227403335),
+    (6.322527182400627, 0.999226208239924),
+    (-6.38547240152125, 0.9947732347017385),
+    (1.6320032732493246, -0.061168737114053566),
+    (2.7782693785236816, -0.9347209535659148),
+    (-1485.683437272176, -0.9582759316547453),
+    (-853195967.1968263, -0.788436311709926),
+    (7806113.352995523, -0.9862066773964108),
+    (2074260271876502.5, 0.7993714097837684),
+    (3688551956.2600546, -0.36772608742081614),
+    (-703.8347064927481, 0.9930517137827372),
+    (-6782416342494161.0, 0.8130393106227475),
+    (2.8552906545367616e-06, 0.9999999999959237),
+    (1e-08, 1.0),
+    (1.5707963267948966, 6.123233995736766e-17),
+    (3.141592653589793, -1.0),
+    (1e+22, 0.523214785395139),
+    (1e+300, -0.5753861119575491),
+    (5.319372648326541e+255, -4.687165924254628e-19),
+    // Within 2^-98 of a rounding midpoint
+    (1.4112508933125247e-06, 0.9999999999990042),
+    (1.4160415971205896e-06, 0.9999999999989975),
+];
+const EXP_CR_REFERENCE: [(f64, f64); 26] = [
+    (1.0078601524580577, 2.7397321289339507),
+    (15.005499822937153, 3287045.9206666113),
+    (9.177811577568704, 9679.945765997358),
+    (-8.482489404392538, 0.00020706259804652326),
+    (19.206993899703285, 219528780.36126494),
+    (-15.277368869801515, 2.3180510805653786e-07),
+    (-3.2750871285909113, 0.03781357444233361),
+    (10.285637182609975, 29308.62682657421),
+    (-524.0144866036261, 2.6509408083625284e-228),
+    (-34.047651908178636, 1.6341527702819425e-15),
+    (-687.9926482530257, 1.6165504905667154e-299),
+    (226.58585540101058, 2.5408947622329973e+98),
+    (366.68603947343036, 1.7771477427309815e+159),
+    (88.17971716331624, 1.9768084090290424e+38),
+    (527.9447384021114, 1.9208198622311897e+229),
+    (-288.8111163188673, 3.723281482440732e-126),
+    (1e-17, 1.0),
+    (-1e-10, 0.9999999999),
+    (-708.5, 2.006132305331306e-308),
+    (-720.0, 2.0322308024e-313),
+    (-744.5, 5e-324),
+    (-745.1332191019411, 5e-324),
+    (-745.14, 0.0),
+    (709.78, 1.7928227943945155e+308),
+    (709.782712893384, 1.7976931348622732e+308),
+    // Within 2^-98 of a rounding midpoint
+    (7.541752774995959e-10, 1.0000000007541754),
+];
+const LN_CR_REFERENCE: [(f64, f64); 24] = [
+    (1.5039027871281303e+117, 269.810519467524),
+    (4.187224659988335e+56, 130.37680334981732),
+    (8.652120702622016e+47, 110.37930382954836),
+    (5.2868718171729834e-27, -60.50457077878753),
+    (9.564632932819865e+203, 469.68284610386974),
+    (6.436608060401028e+266, 614.3496364390825),
+    (2.8774146589829603e-16, -35.78446928477305),
+    (3.099725847940153e+98, 226.78465278484853),
+    (0.5910041413958296, -0.5259322541626389),
+    (1.5522380319566358, 0.4396977811069611),
+    (1.4706932817915033, 0.38573390987909034),
+    (1.9896439091999512, 0.687955682624184),
+    (1.7328871799145724, 0.5497889075840525),
+    (0.9268932981412239, -0.07591682452758577),
+    (1.0786871636700663, 0.07574471251391282),
+    (1.5029790738262823, 0.40744918773730715),
+    (1.0000001, 9.999999505838704e-08),
+    (2.0, 0.6931471805599453),
+    (5e-324, -744.4400719213812),
+    (2.2250738585072014e-308, -708.3964185322641),
+    (1.7976931348623157e+308, 709.782712893384),
+    // Within 2^-98 of a rounding midpoint
+    (0.9999999999999998, -2.2204460492503136e-16),
+    (0.9999999999999987, -1.3322676295501888e-15),
+    (1.0000000000000013, 1.332267629550187e-15),
+];
+const SQRT_CR_REFERENCE: [(f64, f64); 27] = [
+    (3.449505433417071e-287, 5.873249044112697e-144),
+    (1.0403315748636692e-23, 3.225417143353196e-12),
+    (6.745704984784291e-200, 2.5972495037605247e-100),
+    (1.8091588001970008e-230, 1.345049738930498e-115),
+    (2.358585361564142e-265, 4.856526908773534e-133),
+    (8.705487244450509e+160, 2.950506269176615e+80),
+    (4.019140711064967e-223, 6.339669321869214e-112),
+    (3.7059556537015355e-152, 1.9250858821625427e-76),
+    (1.5637988125329083, 1.2505194170955156),
+    (3.4856878965051976, 1.8669997044737843),
+    (0.3223252048005545, 0.567736915129318),
+    (1.7967496037973238, 1.340428888004628),
+    (2.1977596365761496, 1.4824842786944317),
+    (3.53353530576605, 1.8797700140618399),
+    (3.2771193513429653, 1.8102815668682497),
+    (3.4559378787940607, 1.8590152981603085),
+    (5e-324, 2.2227587494850775e-162),
+    (1e-310, 9.999999999999986e-156),
+    (2.0, 1.4142135623730951),
+    (4.0, 2.0),
+    // Within 2^-98 of a rounding midpoint
+    (1.7976931348623157e+308, 1.3407807929942596e+154),
+    (1.0000000000000002, 1.0),
+    (1.0000000000000007, 1.0000000000000002),
+    (1.000000000000001, 1.0000000000000004),
+    (1.0000000000000016, 1.0000000000000007),
+    (3.872591914849319e-121, 6.223015277861142e-61),
+    (2.5822498780869115e+120, 1.606938044258991e+60),
+];
+
+#[test]
+fn test_sin_cr() {
+    const fn inner() -> bool {
+        let mut i = 0;
+        while i < SIN_CR_REFERENCE.len() {
+            let (x, expected) = SIN_CR_REFERENCE[i];
+            if sin_cr(x).to_bits() != expected.to_bits() {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_cos_cr() {
+    const fn inner() -> bool {
+        let mut i = 0;
+        while i < COS_CR_REFERENCE.len() {
+            let (x, expected) = COS_CR_REFERENCE[i];
+            if cos_cr(x).to_bits() != expected.to_bits() {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_exp_cr() {
+    const fn inner() -> bool {
+        let mut i = 0;
+        while i < EXP_CR_REFERENCE.len() {
+            let (x, expected) = EXP_CR_REFERENCE[i];
+            if exp_cr(x).to_bits() != expected.to_bits() {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_ln_cr() {
+    const fn inner() -> bool {
+        let mut i = 0;
+        while i < LN_CR_REFERENCE.len() {
+            let (x, expected) = LN_CR_REFERENCE[i];
+            if ln_cr(x).to_bits() != expected.to_bits() {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_sqrt_cr() {
+    const fn inner() -> bool {
+        let mut i = 0;
+        while i < SQRT_CR_REFERENCE.len() {
+            let (x, expected) = SQRT_CR_REFERENCE[i];
+            if sqrt_cr(x).to_bits() != expected.to_bits() {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_cr_special_values() {
+    const fn inner() -> bool {
+        sin_cr(f64::INFINITY).is_nan()
+            && sin_cr(-0.0).to_bits() == (-0.0f64).to_bits()
+            && cos_cr(f64::NAN).is_nan()
+            && exp_cr(f64::NEG_INFINITY) == 0.0
+            && exp_cr(f64::INFINITY) == f64::INFINITY
+            && exp_cr(0.0) == 1.0
+            && ln_cr(0.0) == f64::NEG_INFINITY
+            && ln_cr(-1.0).is_nan()
+            && ln_cr(1.0) == 0.0
+            && sqrt_cr(-1.0).is_nan()
+            && sqrt_cr(-0.0).to_bits() == (-0.0f64).to_bits()
+            && sqrt_cr(f64::INFINITY) == f64::INFINITY
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+const_poly_fn!(
+    fn sin_exp_cr(x, y) {
+        [1.0, SinCr, ExpCr]
+    }
+);
+
+#[test]
+fn test_cr_var_functions() {
+    // Selected per term, whether or not the correctly-rounded feature is enabled
+    const fn inner() -> bool {
+        const POLY: Polynomial<2> = const_poly!([1.0, SinCr, ExpCr]);
+        const X: f64 = 1e22;
+        const Y: f64 = 7.541752774995959e-10;
+
+        SinCr.evaluate(X) == sin_cr(X)
+            && CosCr.evaluate(X) == cos_cr(X)
+            && ExpCr.evaluate(Y) == exp_cr(Y)
+            && LnCr.evaluate(0.9999999999999998) == ln_cr(0.9999999999999998)
+            && SqrtCr.evaluate(1.0000000000000002) == sqrt_cr(1.0000000000000002)
+            && POLY.evaluate(&[X, Y]) == sin_cr(X) * exp_cr(Y)
+            && sin_exp_cr(X, Y) == POLY.evaluate(&[X, Y])
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[cfg(feature = "correctly-rounded")]
+#[test]
+fn test_approx_functions_are_correctly_rounded() {
+    use const_poly::function_approximations::*;
+
+    for (x, expected) in SIN_CR_REFERENCE {
+        assert_eq!(sin_approx(x).to_bits(), expected.to_bits());
+    }
+    assert_eq!(cos_approx(1e22), 0.523214785395139);
+    assert_eq!(exp_approx(7.541752774995959e-10), 1.0000000007541754);
+    assert_eq!(ln_approx(0.9999999999999998), -2.2204460492503136e-16);
+    assert_eq!(sqrt_approx(1.0000000000000002), 1.0);
+}
@@ -0,0 +1,149 @@
+use const_poly::VarFunction::*;
+use const_poly::dense::Scheme;
+use const_poly::{DensePoly, Polynomial, const_poly, dense_to_poly, poly_to_dense};
+use static_assertions::const_assert;
+
+/// Helper function to compare floating point numbers within a tolerance
+const fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    let diff = if a > b { a - b } else { b - a };
+    diff < epsilon
+}
+
+#[test]
+fn test_dense_poly_horner() {
+    const fn inner() -> bool {
+        // Chebyshev T_8(x) = 128x⁸ - 256x⁶ + 160x⁴ - 32x² + 1 = cos(8·acos(x))
+        const T8: DensePoly<8> =
+            DensePoly::new(&[1.0, 0.0, -32.0, 0.0, 160.0, 0.0, -256.0, 0.0, 128.0]);
+        // cos(8·acos(x)) at x = 0.3, 0.5, 0.9 and 1
+        const POINTS: [(f64, f64); 4] = [
+            (0.3, -0.76222592),
+            (0.5, -0.5),
+            (0.9, -0.89309312),
+            (1.0, 1.0),
+        ];
+
+        let mut i = 0;
+        while i < POINTS.len() {
+            let (x, expected) = POINTS[i];
+            if !approx_eq(T8.evaluate(x), expected, 1e-13) {
+                return false;
+            }
+            i += 1;
+        }
+
+        // Degree zero: a constant
+        const C: DensePoly<0> = DensePoly::new(&[-2.5]);
+        C.evaluate(1e300) == -2.5
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_dense_poly_conversions() {
+    const fn inner() -> bool {
+        // Unordered terms, a repeated power and an Identity term
+        const F: Polynomial<1> = const_poly!({
+            [2.0, Pow(4)],
+            [-1.0, Identity],
+            [0.5, Pow(4)],
+            [3.0, Pow(0)],
+            [1.0, Pow(1)]
+        });
+        const P: DensePoly<4> = poly_to_dense!(F);
+        const BACK: Polynomial<1> = dense_to_poly!(P);
+
+        let coeffs = P.coeffs();
+        if coeffs.len() != 5
+            || coeffs[0] != 3.0
+            || coeffs[1] != 0.0
+            || coeffs[2] != 0.0
+            || coeffs[3] != 0.0
+            || coeffs[4] != 2.5
+        {
+            return false;
+        }
+        if BACK.num_terms() != 2 {
+            return false;
+        }
+
+        let points = [-1.3, -0.2, 0.0, 0.7, 2.9];
+        let mut i = 0;
+        while i < points.len() {
+            let x = points[i];
+            if !approx_eq(P.evaluate(x), F.evaluate_scalar(x), 1e-12)
+                || !approx_eq(BACK.evaluate_scalar(x), P.evaluate(x), 1e-12)
+            {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_dense_poly_estrin() {
+    const fn inner() -> bool {
+        // Degrees whose coefficient counts are not powers of two, exact on small integers
+        const P2: DensePoly<2> = DensePoly::with_scheme(&[1.0, -2.0, 3.0], Scheme::Estrin);
+        const P5: DensePoly<5> =
+            DensePoly::with_scheme(&[4.0, 0.0, -1.0, 2.0, 0.0, 1.0], Scheme::Estrin);
+        const P10: DensePoly<10> = DensePoly::with_scheme(&[1.0; 11], Scheme::Estrin);
+        if P2.evaluate(3.0) != 22.0 || P5.evaluate(-2.0) != -48.0 || P10.evaluate(2.0) != 2047.0 {
+            return false;
+        }
+
+        // Taylor series of exp up to x²⁰: Estrin agrees with Horner
+        const EXP20: DensePoly<20> = DensePoly::with_scheme(
+            &[
+                1.0,
+                1.0,
+                1.0 / 2.0,
+                1.0 / 6.0,
+                1.0 / 24.0,
+                1.0 / 120.0,
+                1.0 / 720.0,
+                1.0 / 5040.0,
+                1.0 / 40320.0,
+                1.0 / 362880.0,
+                1.0 / 3628800.0,
+                1.0 / 39916800.0,
+                1.0 / 479001600.0,
+                1.0 / 6227020800.0,
+                1.0 / 87178291200.0,
+                1.0 / 1307674368000.0,
+                1.0 / 20922789888000.0,
+                1.0 / 355687428096000.0,
+                1.0 / 6402373705728000.0,
+                1.0 / 121645100408832000.0,
+                1.0 / 2432902008176640000.0,
+            ],
+            Scheme::Estrin,
+        );
+        if !matches!(EXP20.scheme(), Scheme::Estrin) {
+            return false;
+        }
+
+        let points = [-1.0, -0.37, 0.0, 0.5, 1.0];
+        let mut i = 0;
+        while i < points.len() {
+            let x = points[i];
+            if !approx_eq(EXP20.evaluate(x), EXP20.evaluate_horner(x), 1e-15) {
+                return false;
+            }
+            i += 1;
+        }
+
+        approx_eq(EXP20.evaluate(1.0), core::f64::consts::E, 1e-15)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
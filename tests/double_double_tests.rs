@@ -0,0 +1,350 @@
+#![allow(clippy::approx_constant)]
+
+use const_poly::VarFunction::{self, *};
+use const_poly::double_double::{DoubleDouble, powi_dd};
+use const_poly::{Polynomial, const_poly};
+use static_assertions::const_assert;
+
+/// Helper function to compare floating point numbers within a tolerance
+const fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    let diff = if a > b { a - b } else { b - a };
+    diff < epsilon
+}
+
+/// Checks `function.evaluate_dd` against `(x, (hi, lo))` reference values, computed with
+/// mpmath at 60 digits, to a relative error of `1e-30` (absolute where the value is 0).
+const fn matches_reference(function: VarFunction, reference: &[(f64, (f64, f64))]) -> bool {
+    let mut i = 0;
+    while i < reference.len() {
+        let (x, (hi, lo)) = reference[i];
+        let value = function.evaluate_dd(DoubleDouble::from_f64(x));
+        let error = value.sub(DoubleDouble::new(hi, lo)).to_f64();
+        let scale = if hi == 0.0 { 1.0 } else { hi.abs() };
+        if !approx_eq(error / scale, 0.0, 1e-30) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const SIN_REFERENCE: [(f64, (f64, f64)); 15] = [
+    (-1e300, (0.8178819121159085, 4.78135837440326e-17)),
+    (-100.0, (0.5063656411097588, 3.050947053792115e-18)),
+    (-3.0, (-0.1411200080598672, -8.577269787017502e-18)),
+    (-0.5, (-0.479425538604203, 5.103969860556013e-18)),
+    (1e-08, (1e-08, -1.6666666666666668e-25)),
+    (
+        0.7853981633974483,
+        (0.7071067811865475, 4.1036934489363755e-17),
+    ),
+    (1.0, (0.8414709848078965, 1.776845092935536e-18)),
+    (2.5, (0.5984721441039565, -5.521403334082375e-17)),
+    (10.0, (-0.5440211108893698, -3.8949898668223557e-17)),
+    (1000.0, (0.8268795405320025, 3.867581909641312e-17)),
+    // Close to multiples of π/2, where the reduction cancels
+    (
+        91.106186954104,
+        (-1.2379612731767154e-18, 1.1116010032489888e-35),
+    ),
+    (
+        28922353.34055676,
+        (3.3970076597972008e-18, -6.058348677317513e-36),
+    ),
+    (1e16, (0.7796880066069788, -3.933442259574261e-17)),
+    (1e22, (-0.8522008497671888, -6.7806825896773284e-18)),
+    (f64::MAX, (0.004961954789184062, -2.5049377676494104e-19)),
+];
+const COS_REFERENCE: [(f64, (f64, f64)); 15] = [
+    (-1e300, (-0.5753861119575491, 2.6770761918787068e-17)),
+    (-100.0, (0.8623188722876839, 4.334809858136501e-17)),
+    (-3.0, (-0.9899924966004454, -4.2060261566099734e-17)),
+    (-0.5, (0.8775825618903728, -4.2623149864279997e-17)),
+    (1e-08, (1.0, -5e-17)),
+    (
+        0.7853981633974483,
+        (0.7071067811865476, -2.6687565161377232e-17),
+    ),
+    (1.0, (0.5403023058681398, -4.760954612604417e-17)),
+    (2.5, (-0.8011436155469337, -1.8674742705085553e-17)),
+    (10.0, (-0.8390715290764524, -1.4147119988953418e-17)),
+    (1000.0, (0.5623790762907029, 5.1611423543848204e-17)),
+    // Close to multiples of π/2, where the reduction cancels
+    (
+        45.553093477052,
+        (-6.189806365883577e-19, 5.558005016244944e-36),
+    ),
+    (
+        205097628711448.4,
+        (1.9177273808217003e-17, 1.0920445384677565e-33),
+    ),
+    (1e16, (-0.6261681981330862, 4.6130820789045176e-17)),
+    (1e22, (0.523214785395139, -4.7143201076575164e-17)),
+    (f64::MAX, (-0.9999876894265599, -2.6032890267216748e-17)),
+];
+const TAN_REFERENCE: [(f64, (f64, f64)); 7] = [
+    (-3.0, (0.1425465430742778, 1.3870349067877843e-18)),
+    (-0.5, (-0.5463024898437905, -2.9096576216837176e-17)),
+    (1e-08, (1e-08, 3.3333333333333335e-25)),
+    (
+        0.7853981633974483,
+        (0.9999999999999999, 4.9789962505148e-17),
+    ),
+    (1.0, (1.5574077246549023, -6.186464176037592e-17)),
+    (1.5, (14.101419947171719, 6.270315165067482e-16)),
+    (10.0, (0.6483608274590866, 4.076151603893501e-17)),
+];
+const EXP_REFERENCE: [(f64, (f64, f64)); 10] = [
+    (-700.0, (9.85967654375977e-305, 8.5e-322)),
+    (-20.0, (2.061153622438558e-09, -4.19755767595054e-26)),
+    (-1.0, (0.36787944117144233, -1.2428753672788363e-17)),
+    (-1e-10, (0.9999999999, 8.279037096265651e-18)),
+    (0.0, (1.0, 0.0)),
+    (0.5, (1.6487212707001282, -4.731568479435833e-17)),
+    (1.0, (2.718281828459045, 1.4456468917292502e-16)),
+    (3.7, (40.4473043600674, -1.2179541332469429e-15)),
+    (100.0, (2.6881171418161356e+43, -1.6101271449201627e+27)),
+    (700.0, (1.0142320547350045e+304, 1.6666571920734673e+287)),
+];
+const LN_REFERENCE: [(f64, (f64, f64)); 9] = [
+    (1e-300, (-690.7755278982137, -2.3670096176709832e-14)),
+    (1e-05, (-11.512925464970229, 2.790027459050308e-16)),
+    (0.3, (-1.2039728043259361, 8.935521583403776e-17)),
+    (1.0, (0.0, 0.0)),
+    (1.0000001, (9.999999505838704e-08, 1.5249709528441489e-24)),
+    (2.0, (0.6931471805599453, 2.3190468138462996e-17)),
+    (10.0, (2.302585092994046, -2.1707562233822494e-16)),
+    (12345.678, (9.421061321291832, -1.9085650743481053e-16)),
+    (1e+300, (690.7755278982137, 2.3747660028800243e-14)),
+];
+const SQRT_REFERENCE: [(f64, (f64, f64)); 8] = [
+    (1e-300, (1e-150, 6.234187685431415e-168)),
+    (1e-05, (0.0031622776601683794, 6.692335247806306e-20)),
+    (0.3, (0.5477225575051661, 2.890126723719787e-17)),
+    (1.0, (1.0, 0.0)),
+    (2.0, (1.4142135623730951, -9.667293313452913e-17)),
+    (10.0, (3.1622776601683795, -1.9078816970716603e-16)),
+    (12345.678, (111.11110655555547, -4.3594669324288495e-15)),
+    (1e+300, (1e+150, 4.5416783955164837e+133)),
+];
+const ARCTAN_REFERENCE: [(f64, (f64, f64)); 11] = [
+    (-1e300, (-1.5707963267948966, -6.123233995736766e-17)),
+    (
+        -10000000000.0,
+        (-1.5707963266948965, -6.950637705727703e-17),
+    ),
+    (-3.0, (-1.2490457723982544, 2.196203799612311e-18)),
+    (-1.0, (-0.7853981633974483, -3.061616997868383e-17)),
+    (-1e-09, (-1e-09, 3.3333333333333338e-28)),
+    (0.3, (0.2914567944778671, -1.6448555435075034e-17)),
+    (1.0, (0.7853981633974483, 3.061616997868383e-17)),
+    (2.0, (1.1071487177940904, 9.40447137356638e-17)),
+    (
+        1.0000000000000002,
+        (0.7853981633974484, 3.061616997868382e-17),
+    ),
+    (50.0, (1.550798992821746, 3.8815832274879405e-17)),
+    (1e17, (1.5707963267948966, 5.123233995736766e-17)),
+];
+const SINH_REFERENCE: [(f64, (f64, f64)); 12] = [
+    (-710.0, (-1.1169973830808555e308, -5.772538034401481e291)),
+    (-20.0, (-242582597.70489514, 7.865629467297586e-10)),
+    (-1.5, (-2.1292794550948173, -1.8859829935660394e-16)),
+    (-0.999, (-1.173658700352452, 1.848129076778294e-17)),
+    (-1e-09, (-1e-09, -1.6666666666666669e-28)),
+    (0.3, (0.3045202934471426, 9.177343117072388e-18)),
+    (0.5, (0.5210953054937474, -2.3328183476404597e-17)),
+    (1.0, (1.1752011936438014, 7.849672142285669e-17)),
+    (2.0, (3.6268604078470186, 1.9291196578353674e-16)),
+    (50.0, (2.592352764293536e+21, 209515.72666146673)),
+    (700.0, (5.0711602736750225e303, 8.333285960367336e286)),
+    (710.0, (1.1169973830808555e308, 5.772538034401481e291)),
+];
+const COSH_REFERENCE: [(f64, (f64, f64)); 10] = [
+    (-710.0, (1.1169973830808555e308, 5.772538034401481e291)),
+    (-20.0, (242582597.70489514, 1.2745906757087991e-09)),
+    (-1.5, (2.352409615243247, 1.1621929620875948e-16)),
+    (-1e-09, (1.0, 5e-19)),
+    (0.0, (1.0, 0.0)),
+    (0.3, (1.0453385141288605, 7.371812611119388e-18)),
+    (1.0, (1.5430806348152437, 6.606796775006833e-17)),
+    (2.0, (3.7621956910836314, 7.146584908813439e-17)),
+    (50.0, (2.592352764293536e+21, 209515.72666146673)),
+    (710.0, (1.1169973830808555e308, 5.772538034401481e291)),
+];
+
+#[test]
+fn test_sin_dd() {
+    const fn inner() -> bool {
+        matches_reference(Sin, &SIN_REFERENCE)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_cos_dd() {
+    const fn inner() -> bool {
+        matches_reference(Cos, &COS_REFERENCE)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_tan_dd() {
+    const fn inner() -> bool {
+        matches_reference(Tan, &TAN_REFERENCE)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_exp_dd() {
+    const fn inner() -> bool {
+        matches_reference(Exp, &EXP_REFERENCE)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_ln_dd() {
+    const fn inner() -> bool {
+        matches_reference(Ln, &LN_REFERENCE)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_sqrt_dd() {
+    const fn inner() -> bool {
+        matches_reference(Sqrt, &SQRT_REFERENCE)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_arctan_dd() {
+    const fn inner() -> bool {
+        matches_reference(Arctan, &ARCTAN_REFERENCE)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_sinh_dd() {
+    const fn inner() -> bool {
+        matches_reference(Sinh, &SINH_REFERENCE)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_cosh_dd() {
+    const fn inner() -> bool {
+        matches_reference(Cosh, &COSH_REFERENCE)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_double_double_arithmetic() {
+    const fn inner() -> bool {
+        let one = DoubleDouble::from_f64(1.0);
+        let third = one.div_f64(3.0);
+
+        // A tiny addend survives in the low part
+        let tiny = DoubleDouble::from_f64(1e-20);
+        let sum = one.add(tiny);
+        if sum.hi() != 1.0 || sum.lo() != 1e-20 || sum.sub(one).to_f64() != 1e-20 {
+            return false;
+        }
+        // 1/3 · 3 - 1 vanishes to double-double precision
+        if !approx_eq(third.mul_f64(3.0).sub(one).to_f64(), 0.0, 1e-31) {
+            return false;
+        }
+
+        // (1 + 2^-60)² = 1 + 2^-59 + 2^-120
+        const TWO_POW_MINUS_60: f64 = 8.673617379884035e-19;
+        let x = DoubleDouble::new(1.0, TWO_POW_MINUS_60);
+        let square = x.mul(x);
+        if square.hi() != 1.0 || !approx_eq(square.lo(), 2.0 * TWO_POW_MINUS_60, 1e-45) {
+            return false;
+        }
+        if powi_dd(x, 2).lo() != square.lo() || powi_dd(x, -1).mul(x).to_f64() != 1.0 {
+            return false;
+        }
+
+        // Full precision up to the top of the f64 range
+        let huge = DoubleDouble::from_f64(1e305);
+        if huge.mul_f64(1.0).to_f64() != 1e305 || !approx_eq(huge.div(third).hi(), 3e305, 1e290) {
+            return false;
+        }
+        // The exact rounding error is kept where the operands or the product would overflow
+        // the split of TwoProduct
+        let product = huge.mul_f64(0.3);
+        if product.hi() != 3e304 || product.lo() != -1.1102230246251565e288 {
+            return false;
+        }
+        let product = DoubleDouble::from_f64(1.7e308).mul_f64(0.7);
+        if product.hi() != 1.1899999999999998e308 || product.lo() != 8.417205908826694e291 {
+            return false;
+        }
+
+        // new() normalizes: hi is the rounded sum
+        let unnormalized = DoubleDouble::new(1e-20, 1.0);
+        unnormalized.hi() == 1.0 && unnormalized.lo() == 1e-20 && x.neg().hi() == -1.0
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_double_double_operators() {
+    let a = DoubleDouble::from_f64(1.0);
+    let b = DoubleDouble::from_f64(3.0);
+
+    assert_eq!((a / b * b - a).to_f64(), (a.div(b).mul(b).sub(a)).to_f64());
+    assert_eq!((-(a + b)).to_f64(), -4.0);
+}
+
+#[test]
+fn test_polynomial_evaluate_dd() {
+    const fn inner() -> bool {
+        // f(x, y) = x² - 2 + 1e-20·y: the y term is lost in f64 but kept in double-double
+        const POLY: Polynomial<2> = const_poly!({
+            [1.0, Pow(2), Pow(0)],
+            [-2.0, Pow(0), Pow(0)],
+            [1e-20, Pow(0), Identity]
+        });
+        let value = POLY.evaluate_dd(&[1.4142135623730951, 1.0]);
+        if !approx_eq(value.to_f64(), 2.7343234630647693e-16 + 1e-20, 1e-31) {
+            return false;
+        }
+
+        // sin(x)·sin(y) + cos(x)·cos(y) - 1 at x = y is zero to double-double precision
+        const PYTHAGORAS: Polynomial<2> =
+            const_poly!({[1.0, Sin, Sin], [1.0, Cos, Cos], [-1.0, Pow(0), Pow(0)]});
+        approx_eq(PYTHAGORAS.evaluate_dd(&[0.7, 0.7]).to_f64(), 0.0, 1e-31)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
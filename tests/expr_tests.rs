@@ -0,0 +1,152 @@
+use const_poly::CrossFactor::*;
+use const_poly::VarFunction::*;
+use const_poly::expr::{Expr, Node};
+use const_poly::{Polynomial, const_poly, poly_to_expr};
+use static_assertions::const_assert;
+
+/// Helper function to compare floating point numbers within a tolerance
+const fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    let diff = if a > b { a - b } else { b - a };
+    diff < epsilon
+}
+
+#[test]
+fn test_expr_nested_functions() {
+    const MAX_ERROR: f64 = 1e-9;
+
+    const fn inner() -> bool {
+        // sin(x² + 1)
+        const NODES1: [Node; 5] = [
+            Node::Var(0),
+            Node::Powi(0, 2),
+            Node::Const(1.0),
+            Node::Add(1, 2),
+            Node::Apply(Sin, 3),
+        ];
+        const EXPR1: Expr<1, 5> = Expr::new(&NODES1);
+        if !approx_eq(EXPR1.evaluate(&[1.2]), 0.6454349983343707, MAX_ERROR) {
+            return false;
+        }
+
+        // exp(-(x - y)² / 2) / (1 + hypot(x, y))
+        const NODES2: [Node; 12] = [
+            Node::Var(0),
+            Node::Var(1),
+            Node::Sub(0, 1),
+            Node::Powi(2, 2),
+            Node::Const(2.0),
+            Node::Div(3, 4),
+            Node::Neg(5),
+            Node::Apply(Exp, 6),
+            Node::Const(1.0),
+            Node::Hypot(0, 1),
+            Node::Add(8, 9),
+            Node::Div(7, 10),
+        ];
+        const EXPR2: Expr<2, 12> = Expr::new(&NODES2);
+        if !approx_eq(
+            EXPR2.evaluate(&[3.0, 4.0]),
+            0.6065306597126334 / 6.0,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        // x^y + atan2(y, x), with the variable nodes shared by both operands
+        const NODES3: [Node; 5] = [
+            Node::Var(0),
+            Node::Var(1),
+            Node::Powf(0, 1),
+            Node::Atan2(1, 0),
+            Node::Add(2, 3),
+        ];
+        const EXPR3: Expr<2, 5> = Expr::new(&NODES3);
+        if !approx_eq(
+            EXPR3.evaluate(&[2.0, 0.5]),
+            core::f64::consts::SQRT_2 + 0.24497866312686414,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_expr_from_polynomial_is_lossless() {
+    const fn inner() -> bool {
+        const POLY1: Polynomial<3> = const_poly!({
+            [1.2, Pow(2), Pow(-1), Pow(0)],
+            [-0.8, Sin, Identity, Exp],
+            [2.5, Scaled(&Cos, 2.0, 0.5), Ln, Sqrt]
+        });
+        const EXPR1: Expr<3, { POLY1.expr_len() }> = poly_to_expr!(POLY1);
+
+        const POLY2: Polynomial<2> = const_poly!({
+            [2.0, Pow(0), Pow(0); Atan2(1, 0), Hypot(0, 1)],
+            [-1.5, Identity, Pow(0); Linear(Sin, [1.0, 2.0], 0.25), Product(Exp, 0, 1)],
+            [0.3, Gamma, Erf]
+        });
+        const EXPR2: Expr<2, { POLY2.expr_len() }> = poly_to_expr!(POLY2);
+
+        const POLY3: Polynomial<1> = const_poly!([-3.0, Tan]);
+        const NODES3: [Node; POLY3.expr_len()] = POLY3.to_expr_nodes();
+        const EXPR3: Expr<1, { POLY3.expr_len() }> = Expr::new(&NODES3);
+
+        let points = [-1.3, -0.2, 0.0, 0.7, 2.9];
+        let mut i = 0;
+        while i < points.len() {
+            let mut j = 0;
+            while j < points.len() {
+                let (x, y) = (points[i], points[j]);
+                let vars = [x, y, x + 3.1];
+                let a = EXPR1.evaluate(&vars);
+                let b = POLY1.evaluate(&vars);
+                if a.to_bits() != b.to_bits() && !(a.is_nan() && b.is_nan()) {
+                    return false;
+                }
+                if EXPR2.evaluate(&[x, y]).to_bits() != POLY2.evaluate(&[x, y]).to_bits() {
+                    return false;
+                }
+                j += 1;
+            }
+            if EXPR3.evaluate(&[points[i]]).to_bits() != POLY3.evaluate_scalar(points[i]).to_bits()
+            {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_expr_evaluates_shared_nodes_once() {
+    const fn inner() -> bool {
+        // x · 2^100 as 100 nested doublings, each node used twice by the next
+        const fn doubling_chain() -> [Node; 101] {
+            let mut nodes = [Node::Var(0); 101];
+            let mut k = 1;
+            while k < 101 {
+                nodes[k] = Node::Add(k - 1, k - 1);
+                k += 1;
+            }
+            nodes
+        }
+        const NODES: [Node; 101] = doubling_chain();
+        const EXPR: Expr<1, 101> = Expr::new(&NODES);
+
+        EXPR.evaluate(&[3.0]) == 3.0 * 1.2676506002282294e30
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
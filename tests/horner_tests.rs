@@ -0,0 +1,103 @@
+use const_poly::VarFunction::*;
+use const_poly::horner::HornerNode;
+use const_poly::{HornerPoly, Polynomial, const_poly, poly_to_horner};
+use static_assertions::const_assert;
+
+/// Helper function to compare floating point numbers within a tolerance
+const fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    let diff = if a > b { a - b } else { b - a };
+    diff < epsilon
+}
+
+#[test]
+fn test_horner_structure() {
+    const fn inner() -> bool {
+        // f(x, y) = x²y + 3xy + 2x + 1 = ((y)·x + (3y + 2))·x + 1
+        const F: Polynomial<2> = const_poly!({
+            [1.0, Pow(2), Identity],
+            [3.0, Identity, Identity],
+            [2.0, Identity, Pow(0)],
+            [1.0, Pow(0), Pow(0)]
+        });
+        const NODES: [HornerNode; F.horner_len()] = F.to_horner_nodes();
+
+        NODES.len() == 8
+            && matches!(NODES[0], HornerNode::Const(1.0))
+            && matches!(NODES[1], HornerNode::MulPow(0, 1, 1))
+            && matches!(NODES[2], HornerNode::Const(3.0))
+            && matches!(NODES[3], HornerNode::Const(2.0))
+            && matches!(NODES[4], HornerNode::MulAdd(2, 1, 1, 3))
+            && matches!(NODES[5], HornerNode::MulAdd(1, 0, 1, 4))
+            && matches!(NODES[6], HornerNode::Const(1.0))
+            && matches!(NODES[7], HornerNode::MulAdd(5, 0, 1, 6))
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_horner_matches_polynomial() {
+    const fn inner() -> bool {
+        // Unordered terms, like terms, gaps in the exponents and an unused variable
+        const F: Polynomial<3> = const_poly!({
+            [0.5, Pow(7), Pow(0), Pow(3)],
+            [-1.25, Identity, Pow(4), Pow(0)],
+            [2.0, Pow(0), Pow(0), Pow(0)],
+            [0.75, Pow(7), Pow(0), Pow(3)],
+            [-3.0, Pow(2), Pow(4), Identity],
+            [1.5, Pow(0), Pow(9), Pow(0)]
+        });
+        const H: HornerPoly<3, { F.horner_len() }> = poly_to_horner!(F);
+
+        // Exact on small integers
+        if H.evaluate(&[2.0, -1.0, 3.0]) != F.evaluate(&[2.0, -1.0, 3.0]) {
+            return false;
+        }
+
+        let points = [-1.3, -0.2, 0.0, 0.7, 1.1];
+        let mut i = 0;
+        while i < points.len() {
+            let mut j = 0;
+            while j < points.len() {
+                let vars = [points[i], points[j], points[i] * points[j]];
+                if !approx_eq(H.evaluate(&vars), F.evaluate(&vars), 1e-12) {
+                    return false;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        // Terms cancelling out leave a zero coefficient
+        const G: Polynomial<1> = const_poly!({[2.0, Pow(3)], [-2.0, Pow(3)]});
+        const HG: HornerPoly<1, { G.horner_len() }> = poly_to_horner!(G);
+        HG.evaluate(&[1.7]) == 0.0
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_horner_evaluates_shared_nodes_once() {
+    const fn inner() -> bool {
+        // (x + 1)^100 as 100 steps acc · x + acc, each node used twice by the next
+        const fn shared_chain() -> [HornerNode; 101] {
+            let mut nodes = [HornerNode::Const(1.0); 101];
+            let mut k = 1;
+            while k < 101 {
+                nodes[k] = HornerNode::MulAdd(k - 1, 0, 1, k - 1);
+                k += 1;
+            }
+            nodes
+        }
+        const NODES: [HornerNode; 101] = shared_chain();
+        const H: HornerPoly<1, 101> = HornerPoly::new(&NODES);
+
+        H.evaluate(&[1.0]) == 1.2676506002282294e30
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
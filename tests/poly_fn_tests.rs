@@ -0,0 +1,125 @@
+use const_poly::VarFunction::*;
+use const_poly::{Polynomial, const_poly, const_poly_fn};
+use static_assertions::const_assert;
+
+/// Helper function to compare floating point numbers within a relative tolerance
+const fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    let diff = if a > b { a - b } else { b - a };
+    let scale = if b < 0.0 { -b } else { b };
+    diff <= epsilon * scale || diff < epsilon
+}
+
+const_poly_fn!(
+    fn powers(x, y, z) {
+        [1.5, Pow(0), Pow(1), Pow(2)],
+        [-0.5, Pow(3), Pow(4), Pow(5)],
+        [0.25, Pow(6), Pow(7), Pow(8)],
+        [2.0, Pow(-1), Pow(-3), Pow(10)],
+        [-1.0, Identity, Pow(-8), Pow(-11)]
+    }
+);
+
+const POWERS: Polynomial<3> = const_poly!({
+    [1.5, Pow(0), Pow(1), Pow(2)],
+    [-0.5, Pow(3), Pow(4), Pow(5)],
+    [0.25, Pow(6), Pow(7), Pow(8)],
+    [2.0, Pow(-1), Pow(-3), Pow(10)],
+    [-1.0, Identity, Pow(-8), Pow(-11)]
+});
+
+const_poly_fn!(
+    /// A mix of plain, parameterized and scaled functions
+    pub(crate) fn model(t, u) {
+        [0.3, Sin, Exp],
+        [1.2, Scaled(&Cos, 2.0, 0.5), Scaled(&Scaled(&Ln, 3.0, 0.0), 0.5, 4.0)],
+        [-0.7, PowFrac(2, 3), Clamp(-0.5, 0.5)],
+        [2.5, LeakyRelu(0.1), PowF(1.5)],
+        [0.9, Gamma, Scaled(&Pow(3), -1.0, 1.0)],
+        [-1.1, Erf, Sigmoid],
+    }
+);
+
+const MODEL: Polynomial<2> = const_poly!({
+    [0.3, Sin, Exp],
+    [1.2, Scaled(&Cos, 2.0, 0.5), Scaled(&Scaled(&Ln, 3.0, 0.0), 0.5, 4.0)],
+    [-0.7, PowFrac(2, 3), Clamp(-0.5, 0.5)],
+    [2.5, LeakyRelu(0.1), PowF(1.5)],
+    [0.9, Gamma, Scaled(&Pow(3), -1.0, 1.0)],
+    [-1.1, Erf, Sigmoid]
+});
+
+#[test]
+fn test_const_poly_fn_matches_polynomial() {
+    const fn inner() -> bool {
+        let points = [-1.3, -0.2, 0.7, 1.0, 2.9];
+        let mut i = 0;
+        while i < points.len() {
+            let mut j = 0;
+            while j < points.len() {
+                let (x, y) = (points[i], points[j]);
+                let a = powers(x, y, x + y);
+                let b = POWERS.evaluate(&[x, y, x + y]);
+                if !approx_eq(a, b, 1e-14) {
+                    return false;
+                }
+
+                let a = model(x, y + 1.5);
+                let b = MODEL.evaluate(&[x, y + 1.5]);
+                if !(approx_eq(a, b, 1e-14) || (a.is_nan() && b.is_nan())) {
+                    return false;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+const_poly_fn!(
+    fn shared(x, y) {
+        [2.0, Sin, Identity],
+        [3.0, Sin, Pow(2)],
+        [-1.0, Cos, Pow(2)],
+        [0.5, Sin, Identity],
+    }
+);
+
+const SHARED: Polynomial<2> = const_poly!({
+    [2.0, Sin, Identity],
+    [3.0, Sin, Pow(2)],
+    [-1.0, Cos, Pow(2)],
+    [0.5, Sin, Identity]
+});
+
+#[test]
+fn test_const_poly_fn_factors_shared_functions() {
+    const fn inner() -> bool {
+        // sin(x) is shared by terms 0, 1 and 3, and terms 0 and 3 are like terms
+        const GROUPS: [[usize; 4]; 2] = SHARED.factor_prefix_groups();
+        if GROUPS[0][0] != 0 || GROUPS[0][1] != 0 || GROUPS[0][2] != 2 || GROUPS[0][3] != 0 {
+            return false;
+        }
+        if GROUPS[1][0] != 0 || GROUPS[1][1] != 1 || GROUPS[1][2] != 2 || GROUPS[1][3] != 0 {
+            return false;
+        }
+
+        // Computed as sin(x)·(y·(2 + 0.5) + y²·3) + cos(x)·(y²·(-1))
+        let (x, y) = (0.7, 1.3);
+        let (sin_x, cos_x) = (
+            const_poly::function_approximations::sin_approx(x),
+            const_poly::function_approximations::cos_approx(x),
+        );
+        let expected =
+            (0.0 + sin_x * ((0.0 + y * (2.0 + 0.5)) + y * y * 3.0)) + cos_x * (0.0 - y * y);
+        shared(x, y).to_bits() == expected.to_bits()
+            && approx_eq(shared(x, y), SHARED.evaluate(&[x, y]), 1e-15)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
@@ -1,5 +1,11 @@
+#![allow(clippy::approx_constant)]
+
+use const_poly::CrossFactor::*;
 use const_poly::VarFunction::*;
-use const_poly::{Polynomial, const_poly};
+use const_poly::{
+    CrossFactor, Expr, Polynomial, bind_poly, compose_expr, compose_poly, const_poly, embed_poly,
+    permute_poly,
+};
 use static_assertions::const_assert;
 
 const fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
@@ -324,6 +330,771 @@ fn test_cosh_polynomial() {
     assert!(inner());
 }
 
+#[test]
+fn test_inverse_trig_and_hyperbolic_polynomial() {
+    const MAX_ERROR: f64 = 1e-12;
+
+    const ASIN_0_5: f64 = 0.5235987755982989;
+    const ACOS_NEG_0_5: f64 = 2.0943951023931957;
+    const ASINH_2: f64 = 1.4436354751788103;
+    const ACOSH_3: f64 = 1.762747174039086;
+    const ATANH_0_25: f64 = 0.25541281188299536;
+
+    const fn inner() -> bool {
+        const POLY1: Polynomial<2> = const_poly!([2.0, Arcsin, Arccos]);
+        if !approx_eq(
+            POLY1.evaluate(&[0.5, -0.5]),
+            2.0 * ASIN_0_5 * ACOS_NEG_0_5,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        const POLY2: Polynomial<3> = const_poly!([1.5, Asinh, Acosh, Atanh]);
+        if !approx_eq(
+            POLY2.evaluate(&[2.0, 3.0, 0.25]),
+            1.5 * ASINH_2 * ACOSH_3 * ATANH_0_25,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        // Out-of-domain inputs propagate NaN
+        const POLY3: Polynomial<1> = const_poly!([1.0, Arcsin]);
+        if !POLY3.evaluate(&[2.0]).is_nan() {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_fractional_pow_polynomial() {
+    const MAX_ERROR: f64 = 1e-12;
+
+    const fn inner() -> bool {
+        // 2 * x^(3/2) * ∛y
+        const POLY1: Polynomial<2> = const_poly!([2.0, PowFrac(3, 2), Cbrt]);
+        if !approx_eq(POLY1.evaluate(&[4.0, -27.0]), 2.0 * 8.0 * -3.0, MAX_ERROR) {
+            return false;
+        }
+
+        // x^(2/3) of a negative base is real: (∛-8)² = 4
+        const POLY2: Polynomial<1> = const_poly!([1.0, PowFrac(2, 3)]);
+        if !approx_eq(POLY2.evaluate(&[-8.0]), 4.0, MAX_ERROR) {
+            return false;
+        }
+
+        // 0.5 * x^2.5 * y^-0.25
+        const POLY3: Polynomial<2> = const_poly!([0.5, PowF(2.5), PowF(-0.25)]);
+        if !approx_eq(POLY3.evaluate(&[4.0, 16.0]), 0.5 * 32.0 * 0.5, MAX_ERROR) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_statistical_polynomial() {
+    const MAX_ERROR: f64 = 1e-12;
+
+    const ERF_0_5: f64 = 0.5204998778130465;
+    const ERFC_1: f64 = 0.15729920705028513;
+    const NORM_PDF_1: f64 = 0.24197072451914334;
+    const NORM_CDF_NEG_1: f64 = 0.15865525393145705;
+    const PROBIT_0_975: f64 = 1.9599639845400538;
+
+    const fn inner() -> bool {
+        const POLY1: Polynomial<2> = const_poly!([2.0, Erf, Erfc]);
+        if !approx_eq(
+            POLY1.evaluate(&[0.5, 1.0]),
+            2.0 * ERF_0_5 * ERFC_1,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        // Reliability-style model: x² * Φ(y) + 0.5 * φ(x)
+        const POLY2: Polynomial<2> = const_poly!({
+            [1.0, Pow(2), NormCdf],
+            [0.5, NormPdf, Pow(0)]
+        });
+        if !approx_eq(
+            POLY2.evaluate(&[1.0, -1.0]),
+            NORM_CDF_NEG_1 + 0.5 * NORM_PDF_1,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        const POLY3: Polynomial<1> = const_poly!([3.0, Probit]);
+        if !approx_eq(POLY3.evaluate(&[0.975]), 3.0 * PROBIT_0_975, MAX_ERROR) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_gamma_polynomial() {
+    const MAX_ERROR: f64 = 1e-12;
+
+    const GAMMA_2_5: f64 = 1.329340388179137;
+    const LN_GAMMA_10_1: f64 = 13.027526738633236;
+
+    const fn inner() -> bool {
+        const POLY1: Polynomial<2> = const_poly!([2.0, Gamma, LnGamma]);
+        if !approx_eq(
+            POLY1.evaluate(&[2.5, 10.1]),
+            2.0 * GAMMA_2_5 * LN_GAMMA_10_1,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        // Γ(n + 1) is exactly n!
+        const POLY2: Polynomial<2> = const_poly!([0.5, Gamma, Pow(2)]);
+        if !approx_eq(POLY2.evaluate(&[5.0, 3.0]), 0.5 * 24.0 * 9.0, MAX_ERROR) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_bessel_polynomial() {
+    const MAX_ERROR: f64 = 1e-13;
+
+    const J0_5: f64 = -0.1775967713143383;
+    const J1_5: f64 = -0.32757913759146523;
+    const Y0_1: f64 = 0.08825696421567696;
+    const Y1_1: f64 = -0.7812128213002887;
+    const I0_2: f64 = 2.2795853023360673;
+    const K0_2: f64 = 0.11389387274953344;
+
+    const fn inner() -> bool {
+        const POLY1: Polynomial<2> = const_poly!([2.0, BesselJ0, BesselJ1]);
+        if !approx_eq(POLY1.evaluate(&[5.0, 5.0]), 2.0 * J0_5 * J1_5, MAX_ERROR) {
+            return false;
+        }
+
+        const POLY2: Polynomial<2> = const_poly!([1.5, BesselY0, BesselY1]);
+        if !approx_eq(POLY2.evaluate(&[1.0, 1.0]), 1.5 * Y0_1 * Y1_1, MAX_ERROR) {
+            return false;
+        }
+
+        const POLY3: Polynomial<2> = const_poly!({
+            [1.0, BesselI0, Pow(0)],
+            [-1.0, Pow(0), BesselK0]
+        });
+        if !approx_eq(POLY3.evaluate(&[2.0, 2.0]), I0_2 - K0_2, MAX_ERROR) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_activation_polynomial() {
+    const MAX_ERROR: f64 = 1e-14;
+
+    const SIGMOID_1: f64 = 0.7310585786300049;
+    const SOFTPLUS_NEG_1: f64 = 0.3132616875182228;
+    const GELU_0_5: f64 = 0.34573123063700656;
+    const SWISH_2: f64 = 1.7615941559557646;
+
+    const fn inner() -> bool {
+        const POLY1: Polynomial<2> = const_poly!([2.0, Sigmoid, Softplus]);
+        if !approx_eq(
+            POLY1.evaluate(&[1.0, -1.0]),
+            2.0 * SIGMOID_1 * SOFTPLUS_NEG_1,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        // Per-feature transforms of a small surrogate model
+        const POLY2: Polynomial<3> = const_poly!({
+            [0.5, Relu, Pow(0), Pow(0)],
+            [-1.5, Pow(0), LeakyRelu(0.1), Pow(0)],
+            [1.0, Pow(0), Pow(0), Gelu],
+            [2.0, Swish, Pow(0), Pow(0)]
+        });
+        if !approx_eq(
+            POLY2.evaluate(&[2.0, -3.0, 0.5]),
+            0.5 * 2.0 + -1.5 * -0.3 + GELU_0_5 + 2.0 * SWISH_2,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_non_smooth_polynomial() {
+    const MAX_ERROR: f64 = 1e-15;
+
+    const fn inner() -> bool {
+        const POLY1: Polynomial<3> = const_poly!([2.0, Abs, Sign, Heaviside]);
+        if !approx_eq(POLY1.evaluate(&[-1.5, -4.0, 0.0]), -1.5, MAX_ERROR) {
+            return false;
+        }
+
+        const POLY2: Polynomial<3> = const_poly!([1.0, Floor, Ceil, Round]);
+        if !approx_eq(
+            POLY2.evaluate(&[2.7, -2.7, 2.5]),
+            2.0 * -2.0 * 3.0,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        // Saturation 3 * clamp(x, -1, 1) plus a term x * H(y) that is switched off for y < 0
+        const POLY3: Polynomial<2> = const_poly!({
+            [3.0, Clamp(-1.0, 1.0), Pow(0)],
+            [1.0, Identity, Heaviside]
+        });
+        if !approx_eq(POLY3.evaluate(&[4.0, -0.2]), 3.0, MAX_ERROR) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_reciprocal_and_degree_trig_polynomial() {
+    const MAX_ERROR: f64 = 1e-12;
+
+    const fn inner() -> bool {
+        // sec(0) * csc(π/2) * cot(π/4) = 1
+        const POLY1: Polynomial<3> = const_poly!([2.0, Sec, Csc, Cot]);
+        if !approx_eq(
+            POLY1.evaluate(&[
+                0.0,
+                core::f64::consts::FRAC_PI_2,
+                core::f64::consts::FRAC_PI_4,
+            ]),
+            2.0,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        // Degree arguments are exact at the special angles, even after many turns
+        const POLY2: Polynomial<3> = const_poly!([4.0, SinDeg, CosDeg, TanDeg]);
+        if POLY2.evaluate(&[30.0 + 360.0 * 1000.0, -60.0, 405.0]) != 1.0 {
+            return false;
+        }
+
+        // sin(x°) - cos(y°) vanishes for complementary angles
+        const POLY3: Polynomial<2> = const_poly!({
+            [1.0, SinDeg, Pow(0)],
+            [-1.0, Pow(0), CosDeg]
+        });
+        if !approx_eq(POLY3.evaluate(&[17.0, 73.0]), 0.0, MAX_ERROR) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_lambert_w_polynomial() {
+    const MAX_ERROR: f64 = 1e-13;
+
+    const fn inner() -> bool {
+        // W(x·e^x) = x, and W(e) = 1
+        const POLY1: Polynomial<2> = const_poly!([3.0, LambertW, LambertW]);
+        if !approx_eq(
+            POLY1.evaluate(&[2.0 * 7.38905609893065, core::f64::consts::E]),
+            6.0,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        // W(-ln(2)/2) = -ln(2), since -ln(2)·e^(-ln(2)) = -ln(2)/2
+        const POLY2: Polynomial<1> = const_poly!([2.0, LambertW]);
+        if !approx_eq(
+            POLY2.evaluate(&[-core::f64::consts::LN_2 / 2.0]),
+            -2.0 * core::f64::consts::LN_2,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        // Operating point check: W(x)·e^y = x at y = W(10)
+        const POLY3: Polynomial<2> = const_poly!([1.0, LambertW, Exp]);
+        if !approx_eq(POLY3.evaluate(&[10.0, 1.7455280027406994]), 10.0, MAX_ERROR) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_elliptic_polynomial() {
+    const MAX_ERROR: f64 = 1e-13;
+
+    const fn inner() -> bool {
+        // Perimeter of an ellipse with semi-axes a = 1, b: 4·a·E(1 - b²); a circle gives 2π
+        const POLY1: Polynomial<1> = const_poly!([4.0, EllipticE]);
+        if !approx_eq(
+            POLY1.evaluate(&[0.0]),
+            2.0 * core::f64::consts::PI,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        // Legendre's relation E(m)K(1-m) + K(m)E(1-m) - K(m)K(1-m) = π/2
+        const POLY2: Polynomial<2> = const_poly!({
+            [1.0, EllipticE, EllipticK],
+            [1.0, EllipticK, EllipticE],
+            [-1.0, EllipticK, EllipticK]
+        });
+        if !approx_eq(
+            POLY2.evaluate(&[0.3, 0.7]),
+            core::f64::consts::FRAC_PI_2,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_scaled_polynomial() {
+    const MAX_ERROR: f64 = 1e-12;
+    const PI: f64 = core::f64::consts::PI;
+
+    const fn inner() -> bool {
+        // 2·sin(2π·50·t + π/2)·exp(-x / 0.5) at t = 0.01, x = 0.5: 2·sin(1.5π)·e^(-1) = -2/e
+        const POLY1: Polynomial<2> = const_poly!([
+            2.0,
+            Scaled(&Sin, 2.0 * PI * 50.0, PI / 2.0),
+            Scaled(&Exp, -1.0 / 0.5, 0.0)
+        ]);
+        if !approx_eq(
+            POLY1.evaluate(&[0.01, 0.5]),
+            -2.0 / core::f64::consts::E,
+            1e-9,
+        ) {
+            return false;
+        }
+
+        // Scaled factors nest and mix with plain ones: (2x - 1)² · sqrt(y / 4 + 1)
+        const POLY2: Polynomial<2> = const_poly!({
+            [1.0, Scaled(&Pow(2), 2.0, -1.0), Scaled(&Scaled(&Sqrt, 0.5, 1.0), 0.5, 0.0)],
+            [-1.0, Pow(0), Identity]
+        });
+        if !approx_eq(POLY2.evaluate(&[2.0, 12.0]), 9.0 * 2.0 - 12.0, MAX_ERROR) {
+            return false;
+        }
+
+        // A unit scale with zero offset is the plain function
+        const POLY3: Polynomial<1> = const_poly!({
+            [1.0, Scaled(&Gamma, 1.0, 0.0)],
+            [-1.0, Gamma]
+        });
+        if !approx_eq(POLY3.evaluate_scalar(4.5), 0.0, MAX_ERROR) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_cross_factor_polynomial() {
+    const MAX_ERROR: f64 = 1e-12;
+
+    const fn inner() -> bool {
+        // Polar coordinates of (x, y) = (-3, 4): r = 5, θ = atan2(4, -3)
+        const RADIUS: Polynomial<2> = const_poly!([1.0, Pow(0), Pow(0); Hypot(0, 1)]);
+        const ANGLE: Polynomial<2> = const_poly!([1.0, Pow(0), Pow(0); Atan2(1, 0)]);
+        if !approx_eq(RADIUS.evaluate(&[-3.0, 4.0]), 5.0, MAX_ERROR)
+            || !approx_eq(ANGLE.evaluate(&[-3.0, 4.0]), 2.214297435588181, MAX_ERROR)
+        {
+            return false;
+        }
+
+        // sin(x + y) - sin(x)cos(y) - cos(x)sin(y) = 0
+        const POLY1: Polynomial<2> = const_poly!({
+            [1.0, Pow(0), Pow(0); Linear(Sin, [1.0, 1.0], 0.0)],
+            [-1.0, Sin, Cos],
+            [-1.0, Cos, Sin]
+        });
+        if !approx_eq(POLY1.evaluate(&[0.4, 1.1]), 0.0, 1e-9) {
+            return false;
+        }
+
+        // 2 * x * exp(x * y) * atan2(y, x) mixes per-variable and several cross factors
+        const POLY2: Polynomial<2> =
+            const_poly!([2.0, Identity, Pow(0); Product(Exp, 0, 1), Atan2(1, 0)]);
+        if !approx_eq(
+            POLY2.evaluate(&[1.0, 1.0]),
+            2.0 * core::f64::consts::E * core::f64::consts::FRAC_PI_4,
+            MAX_ERROR,
+        ) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_polynomial_compose() {
+    const MAX_ERROR: f64 = 1e-9;
+
+    // f(x, y) = 1.5·x²·y⁻¹ - 2·x + 0.5·e^y
+    const F: Polynomial<2> = const_poly!({
+        [1.5, Pow(2), Pow(-1)],
+        [-2.0, Identity, Pow(0)],
+        [0.5, Pow(0), Exp]
+    });
+
+    // Change of coordinates x = u - 2v + 1, y = 3u (unit conversion), into two variables
+    const SUBS: [Polynomial<2>; 2] = [
+        const_poly!({
+            [1.0, Identity, Pow(0)],
+            [-2.0, Pow(0), Identity],
+            [1.0, Pow(0), Pow(0)]
+        }),
+        const_poly!([3.0, Identity, Pow(0)]),
+    ];
+    const G: Polynomial<2> = compose_poly!(F, SUBS);
+
+    // A constant x = 2 folds into the coefficients, and y = 0.5v turns e^y into a
+    // Scaled factor
+    const SUBS_CONST: [Polynomial<1>; 2] =
+        [const_poly!([2.0, Pow(0)]), const_poly!([0.5, Identity])];
+    const H: Polynomial<1> = compose_poly!(F, SUBS_CONST);
+
+    // Composition of a transcendental factor: sin(2πt + π/4) from sin(x), x = 2πt + π/4
+    const WAVE: Polynomial<1> = const_poly!([2.0, Sin]);
+    const PHASE: [Polynomial<1>; 1] = [const_poly!({
+        [2.0 * core::f64::consts::PI, Identity],
+        [core::f64::consts::FRAC_PI_4, Pow(0)]
+    })];
+    const SHIFTED_WAVE: Polynomial<1> = compose_poly!(WAVE, PHASE);
+
+    const fn inner() -> bool {
+        // G(u, v) = F(u - 2v + 1, 3u)
+        let points = [(0.7, -0.4), (1.3, 0.25), (2.0, 1.5)];
+        let mut i = 0;
+        while i < points.len() {
+            let (u, v) = points[i];
+            let expected = F.evaluate(&[u - 2.0 * v + 1.0, 3.0 * u]);
+            if !approx_eq(G.evaluate(&[u, v]), expected, MAX_ERROR) {
+                return false;
+            }
+            if !approx_eq(H.evaluate(&[v]), F.evaluate(&[2.0, 0.5 * v]), MAX_ERROR) {
+                return false;
+            }
+            i += 1;
+        }
+
+        // (u - 2v + 1)² expands to 9 terms without collecting, times 1 for y⁻¹,
+        // plus 3 for the linear term and 1 for the exponential
+        if F.compose_len(&SUBS) != 9 + 3 + 1 {
+            return false;
+        }
+
+        // 2·sin(2π/8 + π/4) = 2·sin(π/2), within the accuracy of sin_approx()
+        if !approx_eq(SHIFTED_WAVE.evaluate_scalar(0.125), 2.0, 1e-8) {
+            return false;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_polynomial_compose_expr() {
+    const MAX_ERROR: f64 = 1e-9;
+
+    // f(x, y) = sin(x)·y² + atan2(y, x), with x = u² + v² and y = u·v - 1
+    const F: Polynomial<2> = const_poly!({
+        [1.0, Sin, Pow(2)],
+        [1.0, Pow(0), Pow(0); Atan2(1, 0)]
+    });
+    const SUBS: [Polynomial<2>; 2] = [
+        const_poly!({[1.0, Pow(2), Pow(0)], [1.0, Pow(0), Pow(2)]}),
+        const_poly!({[1.0, Identity, Identity], [-1.0, Pow(0), Pow(0)]}),
+    ];
+    const G: Expr<2, { F.compose_expr_len(&SUBS) }> = compose_expr!(F, SUBS);
+
+    const fn inner() -> bool {
+        let points = [(0.3, 0.4), (-1.1, 0.9), (1.5, -0.2)];
+        let mut i = 0;
+        while i < points.len() {
+            let (u, v) = points[i];
+            let expected = F.evaluate(&[u * u + v * v, u * v - 1.0]);
+            if !approx_eq(G.evaluate(&[u, v]), expected, MAX_ERROR) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_polynomial_bind() {
+    const MAX_ERROR: f64 = 1e-12;
+
+    // f(x, T, y) = 1.5·sin(x)·exp(-T/300)·y² - 0.2·x·T + atan2(T, 1)·hypot(T, 1)·y
+    //            + cos(x + 2T) + exp(T·y)
+    const F: Polynomial<3> = const_poly!({
+        [1.5, Sin, Scaled(&Exp, -1.0 / 300.0, 0.0), Pow(2)],
+        [-0.2, Identity, Identity, Pow(0)],
+        [1.0, Pow(0), Pow(0), Identity; Atan2(1, 1), Hypot(1, 1)],
+        [1.0, Pow(0), Pow(0), Pow(0); Linear(Cos, [1.0, 2.0, 0.0], 0.0)],
+        [1.0, Pow(0), Pow(0), Pow(0); Product(Exp, 1, 2)]
+    });
+
+    // Temperature fixed at build time
+    const G: Polynomial<2> = bind_poly!(F, [None, Some(0.3), None]);
+
+    // Everything but x fixed
+    const H: Polynomial<1> = bind_poly!(F, [None, Some(0.3), Some(-1.2)]);
+
+    const fn inner() -> bool {
+        let points = [(-1.3, 0.4), (0.2, -0.9), (2.5, 1.7)];
+        let mut i = 0;
+        while i < points.len() {
+            let (x, y) = points[i];
+            if !approx_eq(G.evaluate(&[x, y]), F.evaluate(&[x, 0.3, y]), MAX_ERROR)
+                || !approx_eq(H.evaluate_scalar(x), F.evaluate(&[x, 0.3, -1.2]), MAX_ERROR)
+            {
+                return false;
+            }
+            i += 1;
+        }
+        G.num_terms() == F.num_terms()
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_polynomial_bind_keeps_cross_factors() {
+    const MAX_ERROR: f64 = 1e-12;
+
+    // f(x, T, y) = x·exp(x·T) + 0.5·sin(x·y)·cos(x·T) + 2·cos(x + T - 2y + 0.5)·atan2(y, x)
+    const F: Polynomial<3> = const_poly!({
+        [1.0, Identity, Pow(0), Pow(0); Product(Exp, 0, 1)],
+        [0.5, Pow(0), Pow(0), Pow(0); Product(Sin, 0, 2), Product(Cos, 0, 1)],
+        [2.0, Pow(0), Pow(0), Pow(0); Linear(Cos, [1.0, 1.0, -2.0], 0.5), Atan2(2, 0)]
+    });
+
+    // exp(x·T) cannot be merged into the Identity factor of x, and sin(x·y), the linear
+    // factor and atan2(y, x) keep two free variables: all four stay cross factors
+    const VALUES: [Option<f64>; 3] = [None, Some(0.7), None];
+    const CROSS: [CrossFactor<2>; F.bind_cross_len(&VALUES)] = F.bind_cross(&VALUES);
+    const G: Polynomial<2> = bind_poly!(F, [None, Some(0.7), None]);
+
+    const fn inner() -> bool {
+        let points = [(-1.3, 0.4), (0.2, -0.9), (2.5, 1.7)];
+        let mut i = 0;
+        while i < points.len() {
+            let (x, y) = points[i];
+            if !approx_eq(G.evaluate(&[x, y]), F.evaluate(&[x, 0.7, y]), MAX_ERROR) {
+                return false;
+            }
+            i += 1;
+        }
+        CROSS.len() == 4
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_polynomial_embed_and_permute() {
+    // f(x, y) = 2·sin(x)·y³ + atan2(y, x)·cos(x - 3y + 0.5)
+    const F: Polynomial<2> = const_poly!({
+        [2.0, Sin, Pow(3)],
+        [1.0, Pow(0), Pow(0); Atan2(1, 0), Linear(Cos, [1.0, -3.0], 0.5)]
+    });
+
+    // Same model over (t, y, p, x)
+    const G: Polynomial<4> = embed_poly!(F, 4, [3, 1]);
+
+    // Same model with swapped variables, g(y, x) = f(x, y)
+    const H: Polynomial<2> = permute_poly!(F, [1, 0]);
+
+    // Permuting back restores the original ordering
+    const H_BACK: Polynomial<2> = permute_poly!(H, [1, 0]);
+
+    const fn inner() -> bool {
+        let points = [(0.3, -1.2), (-2.0, 0.7), (1.1, 0.05)];
+        let mut i = 0;
+        while i < points.len() {
+            let (x, y) = points[i];
+            let expected = F.evaluate(&[x, y]).to_bits();
+            if G.evaluate(&[-7.0, y, 123.0, x]).to_bits() != expected
+                || H.evaluate(&[y, x]).to_bits() != expected
+                || H_BACK.evaluate(&[x, y]).to_bits() != expected
+            {
+                return false;
+            }
+            i += 1;
+        }
+        G.num_terms() == F.num_terms() && G.cross_len() == 2
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_polynomial_evaluate_compensated() {
+    const fn inner() -> bool {
+        // (x - y)⁶ expanded, exact value at (3.0001, 3) is 1.0000000000126618e-24
+        const BINOM: Polynomial<2> = const_poly!({
+            [1.0, Pow(6), Pow(0)],
+            [-6.0, Pow(5), Pow(1)],
+            [15.0, Pow(4), Pow(2)],
+            [-20.0, Pow(3), Pow(3)],
+            [15.0, Pow(2), Pow(4)],
+            [-6.0, Pow(1), Pow(5)],
+            [1.0, Pow(0), Pow(6)]
+        });
+        let exact = 1.0000000000126618e-24;
+        if approx_eq(BINOM.evaluate(&[3.0001, 3.0]), exact, 1e-15)
+            || !approx_eq(BINOM.evaluate_compensated(&[3.0001, 3.0]), exact, 1e-27)
+        {
+            return false;
+        }
+
+        // Chebyshev T₁₆ in monomial form near 1
+        const T16: Polynomial<1> = const_poly!({
+            [32768.0, Pow(16)],
+            [-131072.0, Pow(14)],
+            [212992.0, Pow(12)],
+            [-180224.0, Pow(10)],
+            [84480.0, Pow(8)],
+            [-21504.0, Pow(6)],
+            [2688.0, Pow(4)],
+            [-128.0, Pow(2)],
+            [1.0, Pow(0)]
+        });
+        if !approx_eq(
+            T16.evaluate_compensated(&[0.99]),
+            -0.6394904120264371,
+            1e-16,
+        ) {
+            return false;
+        }
+
+        // Large cancelling terms with transcendental factors
+        const TRIG: Polynomial<2> = const_poly!({
+            [1e8, Sin, Identity],
+            [0.5, Cos, Pow(0)],
+            [-1e8, Sin, Pow(1)],
+            [3.0, Pow(2), Exp]
+        });
+        if !approx_eq(
+            TRIG.evaluate_compensated(&[0.7, 1.3]),
+            5.776287195042533,
+            1e-14,
+        ) {
+            return false;
+        }
+
+        // Large powers by square-and-multiply, exact value at 1.0000001 is
+        // 1.1051709126143208 - 5.3959718758169315e-17
+        const POW: Polynomial<1> =
+            const_poly!({[1.0, Pow(1000000)], [-1.1051709126143208, Pow(0)]});
+        if !approx_eq(
+            POW.evaluate_compensated(&[1.0000001]),
+            -5.3959718758169315e-17,
+            1e-25,
+        ) {
+            return false;
+        }
+
+        // Huge operands keep their value, and overflow gives ∞ rather than NaN
+        const LINEAR: Polynomial<1> = const_poly!({ [1.0, Identity] });
+        const EXP: Polynomial<1> = const_poly!({[1.0, Exp], [-1.0, Pow(0)]});
+        if LINEAR.evaluate_compensated(&[1e305]) != 1e305
+            || EXP.evaluate_compensated(&[800.0]) != f64::INFINITY
+        {
+            return false;
+        }
+
+        // Without cancellation the result matches plain evaluation
+        const POLY: Polynomial<2> = const_poly!({[1.5, Sin, Pow(-2)], [0.25, Exp, Sqrt]});
+        approx_eq(
+            POLY.evaluate_compensated(&[0.4, 2.0]),
+            POLY.evaluate(&[0.4, 2.0]),
+            1e-15,
+        )
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
 #[test]
 fn test_polynomial_all_pow_functions() {
     const MAX_ERROR: f64 = 1e-9;
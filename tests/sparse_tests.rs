@@ -0,0 +1,78 @@
+use const_poly::VarFunction::*;
+use const_poly::{Polynomial, SparsePolynomial, SparseTerm, const_poly, sparse_poly};
+use static_assertions::const_assert;
+
+/// Helper function to compare floating point numbers within a tolerance
+const fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    let diff = if a > b { a - b } else { b - a };
+    diff < epsilon
+}
+
+#[test]
+fn test_sparse_polynomial_high_dimensional() {
+    const MAX_ERROR: f64 = 1e-9;
+
+    const fn inner() -> bool {
+        const POLY: SparsePolynomial<200> = sparse_poly!({
+            [2.0, x3: Sin, x17: Pow(2)],
+            [-1.0, x150: Identity],
+            [0.5, x0: Exp, x199: Cos, x3: Pow(-1)],
+            [4.0]
+        });
+
+        let mut vars = [0.0; 200];
+        let mut i = 0;
+        while i < 200 {
+            vars[i] = 0.01 * i as f64;
+            i += 1;
+        }
+
+        let (x0, x3, x17, x150, x199) = (vars[0], vars[3], vars[17], vars[150], vars[199]);
+        let expected = 2.0 * Sin.evaluate(x3) * x17 * x17 - x150
+            + 0.5 * Exp.evaluate(x0) * Cos.evaluate(x199) / x3
+            + 4.0;
+
+        approx_eq(POLY.evaluate(&vars), expected, MAX_ERROR)
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}
+
+#[test]
+fn test_sparse_polynomial_matches_dense() {
+    const fn inner() -> bool {
+        const DENSE: Polynomial<4> = const_poly!({
+            [1.5, Identity, Pow(0), Cos, Pow(0)],
+            [-2.0, Pow(0), Exp, Pow(0), Pow(3)]
+        });
+        const SPARSE: SparsePolynomial<4> = sparse_poly!({
+            [1.5, x0: Identity, x2: Cos],
+            [-2.0, x1: Exp, x3: Pow(3)]
+        });
+        const TERMS: [SparseTerm; 2] = [
+            SparseTerm::new(1.5, &[(2, Cos), (0, Identity)]),
+            SparseTerm::new(-2.0, &[(3, Pow(3)), (1, Exp)]),
+        ];
+        const MANUAL: SparsePolynomial<4> = SparsePolynomial::new(&TERMS);
+
+        let points = [-1.3, -0.2, 0.0, 0.7, 2.9];
+        let mut i = 0;
+        while i < points.len() {
+            let p = points[i];
+            let vars = [p, 0.5 * p, p + 1.0, -p];
+            let dense = DENSE.evaluate(&vars);
+            if !approx_eq(SPARSE.evaluate(&vars), dense, 1e-12)
+                || !approx_eq(MANUAL.evaluate(&vars), dense, 1e-12)
+            {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    const_assert!(inner());
+    assert!(inner());
+}